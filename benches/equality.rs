@@ -0,0 +1,28 @@
+use std::{hint::black_box, time::Instant};
+
+use set::Set;
+
+const ROUNDS: u32 = 100;
+
+fn main() {
+	let left: Set = (0..64_000_000).step_by(3).collect();
+	let mut right = Set::with_maximum(128_000_000);
+
+	right.extend((0..64_000_000).step_by(3));
+
+	let start = Instant::now();
+
+	for _ in 0..ROUNDS {
+		black_box(black_box(left.as_slice()) == black_box(right.as_slice()));
+	}
+
+	println!("eq/words: {:?} per round", start.elapsed() / ROUNDS);
+
+	let start = Instant::now();
+
+	for _ in 0..ROUNDS {
+		black_box(black_box(left.ascending()).eq(black_box(right.ascending())));
+	}
+
+	println!("eq/values: {:?} per round", start.elapsed() / ROUNDS);
+}
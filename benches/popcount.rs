@@ -0,0 +1,54 @@
+use std::{hint::black_box, time::Instant};
+
+use set::{Set, Slice};
+
+const ROUNDS: u32 = 100;
+
+const PATH: &str = if cfg!(feature = "simd") {
+	"simd"
+} else {
+	"scalar"
+};
+
+fn time<F: FnMut() -> usize>(name: &str, path: &str, mut f: F) {
+	let start = Instant::now();
+
+	for _ in 0..ROUNDS {
+		black_box(f());
+	}
+
+	println!("{name}/{path}: {:?} per round", start.elapsed() / ROUNDS);
+}
+
+fn main() {
+	let left: Set = (0..64_000_000).step_by(3).collect();
+	let right: Set = (0..64_000_000).step_by(5).collect();
+	let mut dst = Set::new();
+
+	println!("{} chunks per set", left.maximum() / 64);
+
+	time("union_into", PATH, || {
+		black_box(left.as_slice()).union_into(black_box(right.as_slice()), &mut dst);
+		dst.len()
+	});
+
+	time("union_len", PATH, || {
+		black_box(left.as_slice()).union_len(black_box(right.as_slice()))
+	});
+
+	time("intersection_len", PATH, || {
+		black_box(left.as_slice()).intersection_len(black_box(right.as_slice()))
+	});
+
+	time("from_boxed_words", PATH, || {
+		Set::from_boxed_words(black_box(left.as_words()).into()).len()
+	});
+
+	time("from_words_counted", PATH, || {
+		Slice::from_words_counted(black_box(left.as_words())).len()
+	});
+
+	time("from_words", "const", || {
+		Slice::from_words(black_box(left.as_words())).len()
+	});
+}
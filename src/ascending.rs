@@ -11,6 +11,9 @@ pub struct Ascending<'data> {
 	len: usize,
 	cached: Iter,
 
+	#[cfg(debug_assertions)]
+	chunks: usize,
+
 	_phantom: PhantomData<&'data [Inner]>,
 }
 
@@ -28,14 +31,137 @@ impl<'data> Ascending<'data> {
 			end: 0,
 			len: remaining,
 			cached: Iter::new(0),
+			#[cfg(debug_assertions)]
+			chunks: data.len(),
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Returns a new iterator over the set values, resuming at chunk `offset`
+	/// which holds the values `first`.
+	///
+	/// # Safety
+	///
+	/// The caller ensures that there are at least `len` bits set to 1
+	/// within `first` and the chunks of `data` after `offset`.
+	#[inline]
+	pub(crate) const unsafe fn with_offset(
+		data: &'data [Inner],
+		offset: usize,
+		first: Inner,
+		remaining: usize,
+	) -> Self {
+		Self {
+			start: data.as_ptr(),
+			end: offset + 1,
+			len: remaining,
+			cached: Iter::new(first),
+			#[cfg(debug_assertions)]
+			chunks: data.len(),
 			_phantom: PhantomData,
 		}
 	}
 
+	/// Limits the iterator to at most `len` more values.
+	#[inline]
+	pub(crate) const fn truncated(mut self, len: usize) -> Self {
+		if len < self.len {
+			self.len = len;
+		}
+
+		self
+	}
+
+	/// Returns an iterator over arrays of `N` consecutive values. The final
+	/// values that do not fill an array are left for
+	/// [`ArrayChunks::into_remainder`].
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	#[inline]
+	pub fn array_chunks<const N: usize>(self) -> ArrayChunks<'data, N> {
+		assert!(N != 0, "chunk size must be non-zero");
+
+		ArrayChunks { inner: self }
+	}
+
+	/// Returns the next value without advancing the iterator.
+	#[inline]
+	#[must_use]
+	pub fn peek(&self) -> Option<usize> {
+		self.clone().next()
+	}
+
+	/// Returns the next maximal run of consecutive values as an exclusive
+	/// `(start, end)` range, advancing the iterator past it.
+	pub fn next_run(&mut self) -> Option<(usize, usize)> {
+		if self.len == 0 {
+			return None;
+		}
+
+		if self.cached.len() == 0 {
+			// SAFETY: We have at least 1 bit left and the current chunk does
+			// not contain it.
+			unsafe { self.find_non_zero() };
+		}
+
+		// Runs are clamped to `len` so that truncated iterators, such as
+		// those of `Borrowed::range`, stop at their last value.
+		let (start, length) = self.cached.next_run()?;
+		let start = crate::inner::chunk_to_bits(self.end - 1) + usize::from(start);
+		let length = usize::from(length).min(self.len);
+		let mut end = start + length;
+
+		self.len -= length;
+
+		while self.cached.len() == 0
+			&& self.len != 0
+			&& end == crate::inner::chunk_to_bits(self.end)
+		{
+			// SAFETY: We have at least 1 bit left past the current chunk.
+			let inner = unsafe { self.read_chunk(self.end) };
+
+			if inner & 1 == 0 {
+				break;
+			}
+
+			self.end += 1;
+			self.cached = Iter::new(inner);
+
+			let (_, length) = self.cached.next_run()?;
+			let length = usize::from(length).min(self.len);
+
+			end += length;
+
+			self.len -= length;
+		}
+
+		Some((start, end))
+	}
+
+	/// Advances the iterator past the rest of the current run of consecutive
+	/// values and returns how many were skipped.
+	#[inline]
+	pub fn skip_run(&mut self) -> usize {
+		self.next_run().map_or(0, |(start, end)| end - start)
+	}
+
+	#[inline]
+	unsafe fn read_chunk(&self, offset: usize) -> Inner {
+		#[cfg(debug_assertions)]
+		assert!(
+			offset < self.chunks,
+			"iterator should stay within its chunks, is `len` miscounted?"
+		);
+
+		unsafe { self.start.add(offset).read() }
+	}
+
 	#[inline]
 	unsafe fn find_non_zero(&mut self) {
 		loop {
-			let inner = unsafe { self.start.add(self.end).read() };
+			let inner = unsafe { self.read_chunk(self.end) };
 
 			self.end += 1;
 
@@ -67,6 +193,32 @@ impl<'data> Iterator for Ascending<'data> {
 			.map(|index| crate::inner::chunk_to_bits(self.end - 1) + index)
 	}
 
+	// `try_fold` is not overridden, as that needs the unstable `Try` trait.
+	#[inline]
+	fn fold<B, F: FnMut(B, Self::Item) -> B>(mut self, init: B, mut f: F) -> B {
+		let mut accum = init;
+
+		while self.len != 0 {
+			if self.cached.len() == 0 {
+				// SAFETY: We have at least 1 bit left and the current chunk does
+				// not contain it.
+				unsafe { self.find_non_zero() };
+			}
+
+			let base = crate::inner::chunk_to_bits(self.end - 1);
+			let cached = core::mem::replace(&mut self.cached, Iter::new(0));
+			let count = cached.len().min(self.len);
+
+			accum = cached
+				.take(count)
+				.fold(accum, |accum, index| f(accum, base + usize::from(index)));
+
+			self.len -= count;
+		}
+
+		accum
+	}
+
 	#[inline]
 	fn size_hint(&self) -> (usize, Option<usize>) {
 		(self.len, Some(self.len))
@@ -82,3 +234,43 @@ impl<'data> core::fmt::Debug for Ascending<'data> {
 		f.debug_list().entries(self.clone()).finish()
 	}
 }
+
+/// An iterator over arrays of consecutive values in a set.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayChunks<'data, const N: usize> {
+	inner: Ascending<'data>,
+}
+
+impl<'data, const N: usize> ArrayChunks<'data, N> {
+	/// Returns an iterator over the values not yet grouped, which after
+	/// exhaustion are the fewer than `N` final values.
+	#[inline]
+	pub fn into_remainder(self) -> Ascending<'data> {
+		self.inner
+	}
+}
+
+impl<'data, const N: usize> Iterator for ArrayChunks<'data, N> {
+	type Item = [usize; N];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.inner.len() < N {
+			return None;
+		}
+
+		Some(core::array::from_fn(|_| self.inner.next().unwrap()))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.inner.len() / N;
+
+		(len, Some(len))
+	}
+}
+
+impl<'data, const N: usize> ExactSizeIterator for ArrayChunks<'data, N> {}
+
+impl<'data, const N: usize> FusedIterator for ArrayChunks<'data, N> {}
@@ -1,4 +1,29 @@
-use crate::{ascending::Ascending, descending::Descending, inner::Inner};
+use core::iter::FusedIterator;
+
+use crate::{
+	ascending::Ascending,
+	combine::{Combine, MergeSide},
+	descending::Descending,
+	inner::{Inner, Iter},
+	layout::{BitOrder, LayoutInfo},
+	runs::Runs,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::owned::Owned;
+
+/// The outcome of [`Slice::is_subset_within`](crate::Slice::is_subset_within).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SubsetResult {
+	/// This many values of the set are missing from the other, where 0 means
+	/// it is a subset.
+	Missing(usize),
+	/// More values are missing than the limit allowed.
+	TooMany,
+}
 
 /// A borrowed set of natural numbers.
 #[derive(Clone, Copy)]
@@ -20,13 +45,69 @@ impl<'data> Borrowed<'data> {
 		Self { data, len }
 	}
 
-	/// Returns the largest value the set can store.
+	/// Returns a new instance of the borrowed set over `data`, counting its
+	/// values. This can be used to build sets in const context.
+	#[inline]
+	#[must_use]
+	pub const fn from_words(data: &'data [Inner]) -> Self {
+		let mut len = 0;
+		let mut offset = 0;
+
+		while offset < data.len() {
+			len += data[offset].count_ones() as usize;
+			offset += 1;
+		}
+
+		Self { data, len }
+	}
+
+	/// Returns a new instance of the borrowed set over `data`, counting its
+	/// values with the vectorized popcount when the `simd` feature is enabled.
+	/// Unlike [`Borrowed::from_words`], this is not `const`.
+	#[inline]
+	#[must_use]
+	pub fn from_words_counted(data: &'data [Inner]) -> Self {
+		let len = crate::inner::count_ones(data);
+
+		Self { data, len }
+	}
+
+	/// Returns the chunks backing the set.
+	#[inline]
+	#[must_use]
+	pub const fn as_words(self) -> &'data [Inner] {
+		self.data
+	}
+
+	/// Returns a description of the words backing the set, as documented
+	/// in [`layout`](crate::layout).
+	#[inline]
+	#[must_use]
+	pub const fn layout_info(self) -> LayoutInfo {
+		LayoutInfo {
+			chunk_bits: Inner::BITS,
+			bit_order: BitOrder::Lsb0,
+			chunk_count: self.data.len(),
+		}
+	}
+
+	/// Returns the number of values the set can store, so that exactly the
+	/// values `0..maximum` fit.
+	///
+	/// This always covers whole chunks, so every bit of the backing words is usable.
 	#[inline]
 	#[must_use]
 	pub const fn maximum(self) -> usize {
 		crate::inner::chunk_to_bits(self.data.len())
 	}
 
+	/// Returns the number of values the set could store once shrunk to fit.
+	#[inline]
+	#[must_use]
+	pub fn normalized_maximum(self) -> usize {
+		crate::inner::chunk_to_bits(self.trimmed().len())
+	}
+
 	/// Returns the number of values in the set.
 	#[inline]
 	#[must_use]
@@ -34,6 +115,25 @@ impl<'data> Borrowed<'data> {
 		self.len
 	}
 
+	/// Returns whether the stored length matches the number of set bits.
+	#[inline]
+	#[must_use]
+	pub fn validate(self) -> bool {
+		crate::inner::count_ones(self.data) == self.len
+	}
+
+	/// Returns the number of values in the set if it fits in a `u8`.
+	#[inline]
+	#[must_use]
+	pub const fn small_len(self) -> Option<u8> {
+		if self.len <= u8::MAX as usize {
+			#[allow(clippy::cast_possible_truncation)]
+			Some(self.len as u8)
+		} else {
+			None
+		}
+	}
+
 	/// Returns whether the set contains any value.
 	#[inline]
 	#[must_use]
@@ -50,25 +150,1026 @@ impl<'data> Borrowed<'data> {
 		offset < self.data.len() && crate::inner::get(self.data[offset], index)
 	}
 
+	/// Returns whether the set contains every value in the given inclusive
+	/// range, which holds trivially if the range is empty.
+	#[inline]
+	#[must_use]
+	pub fn contains_inclusive(self, range: core::ops::RangeInclusive<usize>) -> bool {
+		let (start, end) = range.into_inner();
+
+		if start > end {
+			return true;
+		}
+
+		let last = crate::inner::bits_to_chunk(end);
+
+		if last >= self.data.len() {
+			return false;
+		}
+
+		(crate::inner::bits_to_chunk(start)..=last).all(|offset| {
+			let lower = if offset == crate::inner::bits_to_chunk(start) {
+				!crate::inner::mask_below(start)
+			} else {
+				Inner::MAX
+			};
+
+			let upper = if offset == last {
+				crate::inner::mask_below(end) | crate::inner::mask(end)
+			} else {
+				Inner::MAX
+			};
+
+			let mask = lower & upper;
+
+			self.data[offset] & mask == mask
+		})
+	}
+
+	/// Returns whether the set contains the given value, treating negative
+	/// values as absent.
+	#[inline]
+	#[must_use]
+	pub fn contains_i64(self, index: i64) -> bool {
+		usize::try_from(index).is_ok_and(|index| self.contains(index))
+	}
+
+	/// Returns whether the set contains the given value, or `None` if it is
+	/// beyond what the set can store.
+	#[inline]
+	#[must_use]
+	pub const fn get(self, index: usize) -> Option<bool> {
+		let offset = crate::inner::bits_to_chunk(index);
+
+		if offset < self.data.len() {
+			Some(crate::inner::get(self.data[offset], index))
+		} else {
+			None
+		}
+	}
+
+	/// Returns a descending iterator over the stored values starting at `start`.
+	#[inline]
+	pub fn descending_from(self, start: usize) -> Descending<'data> {
+		let offset = crate::inner::bits_to_chunk(start);
+
+		if offset >= self.data.len() {
+			return self.descending();
+		}
+
+		let (rest, last) = self.data.split_at(offset);
+		let first = last[0] & (crate::inner::mask_below(start) | crate::inner::mask(start));
+		let remaining = first.count_ones() as usize + crate::inner::count_ones(rest);
+
+		// SAFETY: `remaining` was counted from the same chunks.
+		unsafe { Descending::with_offset(self.data, offset, first, remaining) }
+	}
+
+	/// Returns a descending iterator over the stored values in the given exclusive range.
+	#[inline]
+	pub fn descending_range(self, start: usize, end: usize) -> Descending<'data> {
+		self.descending_from(end.saturating_sub(1))
+			.truncated(self.count_in_range(start, end))
+	}
+
+	/// Returns the number of values stored in the chunks `chunk_start..chunk_end`.
+	#[inline]
+	#[must_use]
+	pub fn count_chunks(self, chunk_start: usize, chunk_end: usize) -> usize {
+		let chunk_end = chunk_end.min(self.data.len());
+
+		self.data
+			.get(chunk_start..chunk_end)
+			.map_or(0, crate::inner::count_ones)
+	}
+
+	/// Returns the number of values stored in the given exclusive range.
+	#[inline]
+	#[must_use]
+	pub fn count_in_range(self, start: usize, end: usize) -> usize {
+		crate::inner::range_chunks(start, end)
+			.map(|offset| {
+				let inner = self.chunk(offset) & crate::inner::range_mask(start, end, offset);
+
+				inner.count_ones() as usize
+			})
+			.sum()
+	}
+
+	/// Returns the value with exactly `n` smaller values in the set, or `None`
+	/// if there are not that many.
+	#[inline]
+	#[must_use]
+	pub fn select(self, mut n: usize) -> Option<usize> {
+		if n >= self.len {
+			return None;
+		}
+
+		for (offset, &inner) in self.data.iter().enumerate() {
+			let count = inner.count_ones() as usize;
+
+			if n < count {
+				let index = Iter::new(inner).nth(n)?;
+
+				return Some(crate::inner::chunk_to_bits(offset) + usize::from(index));
+			}
+
+			n -= count;
+		}
+
+		None
+	}
+
+	/// Returns the value with exactly `n` larger values in the set, or `None`
+	/// if there are not that many.
+	#[inline]
+	#[must_use]
+	pub fn select_back(self, mut n: usize) -> Option<usize> {
+		if n >= self.len {
+			return None;
+		}
+
+		for (offset, &inner) in self.data.iter().enumerate().rev() {
+			let count = inner.count_ones() as usize;
+
+			if n < count {
+				let index = Iter::new(inner).nth_back(n)?;
+
+				return Some(crate::inner::chunk_to_bits(offset) + usize::from(index));
+			}
+
+			n -= count;
+		}
+
+		None
+	}
+
+	/// Returns the number of values stored in both sets in the given
+	/// exclusive range.
+	#[inline]
+	#[must_use]
+	pub fn count_in_range_of(self, other: Self, start: usize, end: usize) -> usize {
+		let chunks = crate::inner::range_chunks(start, end);
+		let limit = chunks.end.min(self.data.len()).min(other.data.len());
+
+		(chunks.start..limit)
+			.map(|offset| {
+				let mask = crate::inner::range_mask(start, end, offset);
+
+				(self.data[offset] & other.data[offset] & mask).count_ones() as usize
+			})
+			.sum()
+	}
+
+	/// Returns the number of values stored below `value`.
+	#[inline]
+	#[must_use]
+	pub fn rank(self, value: usize) -> usize {
+		self.count_in_range(0, value)
+	}
+
+	/// Returns the number of values stored below `value`.
+	///
+	/// This is the same as [`Borrowed::rank`].
+	#[inline]
+	#[must_use]
+	pub fn count_below(self, value: usize) -> usize {
+		self.rank(value)
+	}
+
+	/// Returns the number of values stored above `value`.
+	#[inline]
+	#[must_use]
+	pub fn count_above(self, value: usize) -> usize {
+		value
+			.checked_add(1)
+			.map_or(0, |value| self.len - self.rank(value))
+	}
+
+	/// Returns the number of empty chunks before the first value, or all of
+	/// them if the set is empty.
+	#[inline]
+	#[must_use]
+	pub fn leading_empty_chunks(self) -> usize {
+		self.data
+			.iter()
+			.position(|&inner| inner != 0)
+			.unwrap_or(self.data.len())
+	}
+
+	/// Returns the number of empty chunks after the last value, or all of
+	/// them if the set is empty.
+	#[inline]
+	#[must_use]
+	pub fn trailing_empty_chunks(self) -> usize {
+		self.data.len() - self.trimmed().len()
+	}
+
+	/// Returns the exclusive range of chunks between the first and last
+	/// non-empty ones, or `None` if the set is empty.
+	#[inline]
+	#[must_use]
+	pub fn chunk_bounds(self) -> Option<(usize, usize)> {
+		let first = self.data.iter().position(|&inner| inner != 0)?;
+		let last = self.data.iter().rposition(|&inner| inner != 0)?;
+
+		Some((first, last + 1))
+	}
+
+	/// Returns a hash of the values that is stable across runs, where equal
+	/// sets always have equal fingerprints.
+	#[inline]
+	#[must_use]
+	pub fn fingerprint(self) -> u64 {
+		const SEED: u64 = 0x51_7C_C1_B7_27_22_0A_95;
+
+		self.trimmed().iter().fold(0, |hash, &inner| {
+			(hash.rotate_left(5) ^ inner).wrapping_mul(SEED)
+		})
+	}
+
+	/// Returns the number of chunks holding at least one value.
+	#[inline]
+	#[must_use]
+	pub fn occupied_chunks(self) -> usize {
+		self.data.iter().filter(|&&inner| inner != 0).count()
+	}
+
+	/// Returns the total number of bits set after applying `predicate` to each chunk.
+	#[inline]
+	#[must_use]
+	pub fn count_where_chunk<P: Fn(Inner) -> Inner>(self, predicate: P) -> usize {
+		self.data
+			.iter()
+			.map(|&inner| predicate(inner).count_ones() as usize)
+			.sum()
+	}
+
+	/// Returns the smallest value in the given exclusive range that is not in the set.
+	#[must_use]
+	pub fn first_missing_in(self, start: usize, end: usize) -> Option<usize> {
+		crate::inner::range_chunks(start, end).find_map(|offset| {
+			let missing = !self.chunk(offset) & crate::inner::range_mask(start, end, offset);
+
+			(missing != 0)
+				.then(|| crate::inner::chunk_to_bits(offset) + missing.trailing_zeros() as usize)
+		})
+	}
+
+	/// Copies the chunks of the set into `dst`, zeroing the rest of it, and
+	/// returns how many chunks were written.
+	///
+	/// # Errors
+	///
+	/// Returns the number of chunks needed if `dst` is too small to hold
+	/// the values, leaving it unchanged.
+	#[inline]
+	pub fn copy_into_words(self, dst: &mut [Inner]) -> Result<usize, usize> {
+		let source = self.trimmed();
+		let Some((head, tail)) = dst.split_at_mut_checked(source.len()) else {
+			return Err(source.len());
+		};
+
+		head.copy_from_slice(source);
+		tail.fill(0);
+
+		Ok(source.len())
+	}
+
+	/// Returns whether every bit of `mask` is set within the given chunk.
+	#[inline]
+	#[must_use]
+	pub const fn all_present_mask(self, chunk_index: usize, mask: Inner) -> bool {
+		self.chunk(chunk_index) & mask == mask
+	}
+
+	/// Returns whether any bit of `mask` is set within the given chunk.
+	#[inline]
+	#[must_use]
+	pub const fn any_present_mask(self, chunk_index: usize, mask: Inner) -> bool {
+		self.chunk(chunk_index) & mask != 0
+	}
+
+	/// Returns an iterator over the bit positions set within the given chunk.
+	#[inline]
+	pub const fn chunk_bits(self, chunk_index: usize) -> Iter {
+		Iter::new(self.chunk(chunk_index))
+	}
+
+	/// Returns the only value stored if the set holds exactly one.
+	#[inline]
+	#[must_use]
+	pub fn as_singleton(self) -> Option<usize> {
+		if self.len != 1 {
+			return None;
+		}
+
+		let offset = self.data.iter().position(|&inner| inner != 0)?;
+
+		Some(crate::inner::chunk_to_bits(offset) + self.data[offset].trailing_zeros() as usize)
+	}
+
+	/// Returns the smallest and largest values stored, scanning from both ends.
+	#[must_use]
+	pub fn bounds(self) -> Option<(usize, usize)> {
+		let first = self.data.iter().position(|&inner| inner != 0)?;
+		let last = self.data.iter().rposition(|&inner| inner != 0)?;
+
+		let start = crate::inner::chunk_to_bits(first) + self.data[first].trailing_zeros() as usize;
+		let end = crate::inner::chunk_to_bits(last)
+			+ (Inner::BITS - 1 - self.data[last].leading_zeros()) as usize;
+
+		Some((start, end))
+	}
+
+	#[inline]
+	pub(crate) const fn chunk(self, offset: usize) -> Inner {
+		if offset < self.data.len() {
+			self.data[offset]
+		} else {
+			0
+		}
+	}
+
+	#[inline]
+	pub(crate) fn trimmed(self) -> &'data [Inner] {
+		let end = self
+			.data
+			.iter()
+			.rposition(|&inner| inner != 0)
+			.map_or(0, |offset| offset + 1);
+
+		&self.data[..end]
+	}
+
+	/// Returns the number of values below [`Borrowed::maximum`] that are not in the set.
+	#[inline]
+	#[must_use]
+	pub const fn gap_count(self) -> usize {
+		self.maximum() - self.len
+	}
+
+	/// Returns the fraction of values below [`Borrowed::maximum`] that are in
+	/// the set, or `0.0` if it can store none.
+	#[allow(clippy::cast_precision_loss)]
+	#[inline]
+	#[must_use]
+	pub const fn density(self) -> f64 {
+		if self.data.is_empty() {
+			0.0
+		} else {
+			self.len as f64 / self.maximum() as f64
+		}
+	}
+
+	/// Returns how many chunks hold each possible number of values, indexed
+	/// by that number.
+	#[inline]
+	#[must_use]
+	pub fn chunk_density_histogram(self) -> [usize; Inner::BITS as usize + 1] {
+		let mut histogram = [0; Inner::BITS as usize + 1];
+
+		for inner in self.data {
+			histogram[inner.count_ones() as usize] += 1;
+		}
+
+		histogram
+	}
+
+	/// Returns the length of the run of consecutive values in the set that
+	/// includes `value`, or 0 if `value` is not in the set.
+	#[inline]
+	#[must_use]
+	pub fn run_length_at(self, value: usize) -> usize {
+		self.run_bounds(value).map_or(0, |(start, end)| end - start)
+	}
+
+	/// Returns the exclusive range of the run of consecutive values in the
+	/// set that includes `value`.
+	pub(crate) fn run_bounds(self, value: usize) -> Option<(usize, usize)> {
+		if !self.contains(value) {
+			return None;
+		}
+
+		let offset = crate::inner::bits_to_chunk(value);
+		let bit = value - crate::inner::chunk_to_bits(offset);
+		let inner = self.data[offset];
+
+		let mut above = (inner >> bit).trailing_ones() as usize;
+
+		if bit + above == Inner::BITS as usize {
+			for next in &self.data[offset + 1..] {
+				above += next.trailing_ones() as usize;
+
+				if *next != Inner::MAX {
+					break;
+				}
+			}
+		}
+
+		let gaps = !inner & crate::inner::mask_below(value);
+		let mut below = bit;
+
+		if gaps == 0 {
+			for previous in self.data[..offset].iter().rev() {
+				below += previous.leading_ones() as usize;
+
+				if *previous != Inner::MAX {
+					break;
+				}
+			}
+		} else {
+			below -= (Inner::BITS - gaps.leading_zeros()) as usize;
+		}
+
+		Some((value - below, value + above))
+	}
+
+	/// Returns the number of positions in `1..maximum` whose presence differs
+	/// from that of the value before it.
+	#[inline]
+	#[must_use]
+	pub fn transition_count(self) -> usize {
+		let mut carry = self.data.first().map_or(0, |&inner| inner & 1);
+
+		self.data
+			.iter()
+			.map(|&inner| {
+				let edges = inner ^ (inner << 1 | carry);
+
+				carry = inner >> (Inner::BITS - 1);
+
+				edges.count_ones() as usize
+			})
+			.sum()
+	}
+
+	/// Returns an iterator over the exclusive `(start, end)` runs of values below
+	/// [`Borrowed::maximum`] that are not in the set.
+	#[inline]
+	pub fn gap_runs(self) -> impl FusedIterator<Item = (usize, usize)> + Clone + 'data {
+		Runs::new(move |offset| !self.data[offset], self.data.len())
+	}
+
+	/// Returns an iterator over the exclusive `(start, end)` runs of values in
+	/// `self` that are not in `other`.
+	#[inline]
+	pub fn difference_runs(
+		self,
+		other: Self,
+	) -> impl FusedIterator<Item = (usize, usize)> + Clone + 'data {
+		Runs::new(
+			move |offset| self.data[offset] & !other.chunk(offset),
+			self.data.len(),
+		)
+	}
+
+	#[cfg(feature = "alloc")]
+	#[inline]
+	pub(crate) fn runs(self) -> Runs<impl Fn(usize) -> Inner + Clone + 'data> {
+		Runs::new(move |offset| self.data[offset], self.data.len())
+	}
+
 	/// Returns an ascending iterator over the stored values.
 	#[inline]
 	pub const fn ascending(self) -> Ascending<'data> {
+		debug_assert!(
+			Self::from_words(self.data).len >= self.len,
+			"`len` should not exceed the number of set bits"
+		);
+
 		// SAFETY: `len` correctly tracks how many set bits exist.
 		unsafe { Ascending::new(self.data, self.len) }
 	}
 
+	/// Returns an ascending iterator over the stored values starting at `start`.
+	#[inline]
+	pub fn ascending_from(self, start: usize) -> Ascending<'data> {
+		let offset = crate::inner::bits_to_chunk(start);
+
+		let Some((&first, rest)) = self.data.get(offset..).and_then(<[Inner]>::split_first) else {
+			// SAFETY: There are no bits to be read.
+			return unsafe { Ascending::new(self.data, 0) };
+		};
+
+		let first = first & !crate::inner::mask_below(start);
+		let remaining = first.count_ones() as usize + crate::inner::count_ones(rest);
+
+		// SAFETY: `remaining` was counted from the same chunks.
+		unsafe { Ascending::with_offset(self.data, offset, first, remaining) }
+	}
+
+	/// Returns an ascending iterator over the stored values in the given exclusive range.
+	#[inline]
+	pub fn range(self, start: usize, end: usize) -> Ascending<'data> {
+		self.ascending_from(start)
+			.truncated(self.count_in_range(start, end))
+	}
+
+	/// Returns an iterator over every value in `0..maximum` paired with
+	/// whether it is in the set.
+	#[inline]
+	pub fn partition_iter(
+		self,
+	) -> impl ExactSizeIterator<Item = (usize, bool)> + FusedIterator + Clone + 'data {
+		(0..self.maximum()).map(move |value| {
+			let inner = self.data[crate::inner::bits_to_chunk(value)];
+
+			(value, crate::inner::get(inner, value))
+		})
+	}
+
+	/// Returns an ascending iterator over the stored values increased by `offset`.
+	///
+	/// # Panics
+	///
+	/// The iterator panics on a value that overflows a `usize` once increased.
+	#[inline]
+	pub fn iter_offset(
+		self,
+		offset: usize,
+	) -> impl ExactSizeIterator<Item = usize> + FusedIterator + Clone + 'data {
+		self.ascending().map(move |value| {
+			value
+				.checked_add(offset)
+				.expect("offset value should fit in a `usize`")
+		})
+	}
+
+	/// Returns an ascending iterator over `(rank, value)` pairs, where `rank`
+	/// is the position of `value` in sorted order.
+	#[inline]
+	pub fn enumerate_ranks(
+		self,
+	) -> impl ExactSizeIterator<Item = (usize, usize)> + FusedIterator + Clone + 'data {
+		self.ascending().enumerate()
+	}
+
+	/// Returns an iterator over every chunk paired with the first value it
+	/// holds.
+	#[inline]
+	pub fn chunks_with_base(
+		self,
+	) -> impl ExactSizeIterator<Item = (usize, Inner)>
+	       + DoubleEndedIterator
+	       + FusedIterator
+	       + Clone
+	       + 'data {
+		self.data
+			.iter()
+			.enumerate()
+			.map(|(offset, &inner)| (crate::inner::chunk_to_bits(offset), inner))
+	}
+
+	/// Returns an iterator over the non-zero chunks paired with the first value
+	/// they hold.
+	#[inline]
+	pub fn chunks_nonzero(
+		self,
+	) -> impl DoubleEndedIterator<Item = (usize, Inner)> + FusedIterator + Clone + 'data {
+		self.chunks_with_base().filter(|&(_, inner)| inner != 0)
+	}
+
+	/// Calls `f` on each value in ascending order, stopping at and returning
+	/// the first error.
+	///
+	/// # Errors
+	///
+	/// Returns the first error returned by `f`.
+	#[inline]
+	pub fn try_for_each_value<E, F: FnMut(usize) -> Result<(), E>>(
+		self,
+		mut f: F,
+	) -> Result<(), E> {
+		for (offset, &inner) in self.data.iter().enumerate() {
+			let base = crate::inner::chunk_to_bits(offset);
+
+			for index in Iter::new(inner) {
+				f(base + usize::from(index))?;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Calls `f` on each value in ascending order.
+	#[inline]
+	pub fn for_each_value<F: FnMut(usize)>(self, mut f: F) {
+		let result: Result<(), core::convert::Infallible> = self.try_for_each_value(|value| {
+			f(value);
+
+			Ok(())
+		});
+
+		let Ok(()) = result;
+	}
+
+	/// Returns an iterator over the non-zero chunks, each paired with the
+	/// first value it can hold and an ascending iterator over its values.
+	#[inline]
+	pub fn chunk_groups(
+		self,
+	) -> impl FusedIterator<Item = (usize, impl ExactSizeIterator<Item = usize> + Clone)> + Clone + 'data
+	{
+		self.chunks_nonzero().map(|(base, inner)| {
+			(
+				base,
+				Iter::new(inner).map(move |index| base + usize::from(index)),
+			)
+		})
+	}
+
 	/// Returns a descending iterator over the stored values.
 	#[inline]
 	pub const fn descending(self) -> Descending<'data> {
+		debug_assert!(
+			Self::from_words(self.data).len >= self.len,
+			"`len` should not exceed the number of set bits"
+		);
+
 		// SAFETY: `len` correctly tracks how many set bits exist.
 		unsafe { Descending::new(self.data, self.len) }
 	}
+
+	/// Returns whether the set holds exactly the values of the sorted slice.
+	#[inline]
+	#[must_use]
+	pub fn eq_sorted(self, values: &[usize]) -> bool {
+		self.len == values.len() && self.ascending().eq(values.iter().copied())
+	}
+
+	/// Returns the number of values in either set, without building the union.
+	#[inline]
+	#[must_use]
+	pub fn union_len(self, other: Self) -> usize {
+		crate::inner::union_count(self.data, other.data)
+	}
+
+	/// Returns the number of values in both sets, without building the
+	/// intersection.
+	#[inline]
+	#[must_use]
+	pub fn intersection_len(self, other: Self) -> usize {
+		crate::inner::intersection_count(self.data, other.data)
+	}
+
+	/// Returns the number of values in `self` but not in `other`.
+	#[inline]
+	#[must_use]
+	pub fn difference_len(self, other: Self) -> usize {
+		self.data
+			.iter()
+			.enumerate()
+			.map(|(offset, &inner)| (inner & !other.chunk(offset)).count_ones() as usize)
+			.sum()
+	}
+
+	/// Returns the number of values in `self` but not in `other`.
+	///
+	/// This is the same as [`Borrowed::difference_len`].
+	#[inline]
+	#[must_use]
+	pub fn andnot_len(self, other: Self) -> usize {
+		self.difference_len(other)
+	}
+
+	/// Returns whether the set holds exactly the values of `iter`, which
+	/// must yield them in ascending order.
+	#[must_use]
+	pub fn eq_iter<I: IntoIterator<Item = usize>>(self, iter: I) -> bool {
+		let mut values = self.ascending();
+		let mut previous = None;
+
+		for value in iter {
+			debug_assert!(previous < Some(value), "values should be ascending");
+
+			if values.next() != Some(value) {
+				return false;
+			}
+
+			previous = Some(value);
+		}
+
+		values.len() == 0
+	}
+
+	/// Returns how many of the smallest values the sets share before the
+	/// first value that is in only one of them.
+	#[must_use]
+	pub fn common_prefix_len(self, other: Self) -> usize {
+		let chunks = self.data.len().max(other.data.len());
+		let mut shared = 0;
+
+		for offset in 0..chunks {
+			let left = self.chunk(offset);
+			let right = other.chunk(offset);
+			let different = left ^ right;
+
+			if different != 0 {
+				let below = crate::inner::mask_below(different.trailing_zeros() as usize);
+
+				return shared + (left & below).count_ones() as usize;
+			}
+
+			shared += left.count_ones() as usize;
+		}
+
+		shared
+	}
+
+	/// Returns whether every value of `self` is also in `other`.
+	#[inline]
+	#[must_use]
+	pub fn is_subset(self, other: Self) -> bool {
+		self.len <= other.len
+			&& self
+				.data
+				.iter()
+				.enumerate()
+				.all(|(offset, &inner)| inner & !other.chunk(offset) == 0)
+	}
+
+	/// Returns whether every value of `other` is also in `self`.
+	#[inline]
+	#[must_use]
+	pub fn is_superset(self, other: Self) -> bool {
+		other.is_subset(self)
+	}
+
+	/// Compares the sets by their number of values, then by their values in
+	/// ascending order.
+	#[inline]
+	#[must_use]
+	pub fn cmp_by_cardinality(self, other: Self) -> core::cmp::Ordering {
+		self.len.cmp(&other.len).then_with(|| self.cmp(&other))
+	}
+
+	/// Compares the sets by inclusion, returning `None` if neither is a
+	/// subset of the other.
+	#[inline]
+	#[must_use]
+	pub fn subset_order(self, other: Self) -> Option<core::cmp::Ordering> {
+		match self.len.cmp(&other.len) {
+			core::cmp::Ordering::Less => self.is_subset(other).then_some(core::cmp::Ordering::Less),
+			core::cmp::Ordering::Equal => (self == other).then_some(core::cmp::Ordering::Equal),
+			core::cmp::Ordering::Greater => other
+				.is_subset(self)
+				.then_some(core::cmp::Ordering::Greater),
+		}
+	}
+
+	/// Returns whether the sets have no values in common.
+	#[inline]
+	#[must_use]
+	pub fn is_disjoint(self, other: Self) -> bool {
+		self.data
+			.iter()
+			.zip(other.data)
+			.all(|(&left, &right)| left & right == 0)
+	}
+
+	/// Returns whether the two sets share any value in the given exclusive range.
+	#[inline]
+	#[must_use]
+	pub fn intersects_in_range(self, other: Self, start: usize, end: usize) -> bool {
+		if self.is_empty() || other.is_empty() {
+			return false;
+		}
+
+		let chunks = crate::inner::range_chunks(start, end);
+		let limit = chunks.end.min(self.data.len()).min(other.data.len());
+
+		(chunks.start..limit).any(|offset| {
+			let mask = crate::inner::range_mask(start, end, offset);
+
+			self.data[offset] & other.data[offset] & mask != 0
+		})
+	}
+
+	/// Returns the number of values only in `self`, in both sets, and only in
+	/// `other`, counted in a single pass.
+	#[inline]
+	#[must_use]
+	pub fn compare_counts(self, other: Self) -> (usize, usize, usize) {
+		let chunks = self.data.len().max(other.data.len());
+
+		(0..chunks).fold((0, 0, 0), |(left, both, right), offset| {
+			let (lhs, rhs) = (self.chunk(offset), other.chunk(offset));
+
+			(
+				left + (lhs & !rhs).count_ones() as usize,
+				both + (lhs & rhs).count_ones() as usize,
+				right + (rhs & !lhs).count_ones() as usize,
+			)
+		})
+	}
+
+	/// Returns how many values of `self` are missing from `other`, stopping
+	/// early once more than `limit` are found.
+	#[inline]
+	#[must_use]
+	pub fn is_subset_within(self, other: Self, limit: usize) -> SubsetResult {
+		let mut missing = 0;
+
+		for (offset, &inner) in self.data.iter().enumerate() {
+			missing += (inner & !other.chunk(offset)).count_ones() as usize;
+
+			if missing > limit {
+				return SubsetResult::TooMany;
+			}
+		}
+
+		SubsetResult::Missing(missing)
+	}
+
+	/// Returns whether `self` is a subset of `other` and the two are not equal.
+	#[inline]
+	#[must_use]
+	pub fn is_proper_subset(self, other: Self) -> bool {
+		self.len != other.len && self.is_subset(other)
+	}
+
+	/// Returns whether `self` is a superset of `other` and the two are not equal.
+	#[inline]
+	#[must_use]
+	pub fn is_proper_superset(self, other: Self) -> bool {
+		other.is_proper_subset(self)
+	}
+
+	/// Returns an ascending iterator over the values in both sets.
+	#[inline]
+	pub fn intersection(self, other: Self) -> impl FusedIterator<Item = usize> + Clone + 'data {
+		let chunks = self.data.len().min(other.data.len());
+		let upper = self.len.min(other.len);
+
+		Combine::new(
+			move |offset| self.data[offset] & other.data[offset],
+			chunks,
+			upper,
+		)
+	}
+
+	/// Returns an ascending iterator over the values in either set, tagged
+	/// with which of the sets they are in.
+	#[inline]
+	pub fn merge_join(
+		self,
+		other: Self,
+	) -> impl FusedIterator<Item = (usize, MergeSide)> + Clone + 'data {
+		let chunks = self.data.len().max(other.data.len());
+
+		(0..chunks).flat_map(move |offset| {
+			let (left, right) = (self.chunk(offset), other.chunk(offset));
+			let base = crate::inner::chunk_to_bits(offset);
+
+			Iter::new(left | right).map(move |index| {
+				let bit = usize::from(index);
+				let side = match (crate::inner::get(left, bit), crate::inner::get(right, bit)) {
+					(true, false) => MergeSide::Left,
+					(false, true) => MergeSide::Right,
+					(true, true) => MergeSide::Both,
+					(false, false) => unreachable!("value should be in one of the words"),
+				};
+
+				(base + bit, side)
+			})
+		})
+	}
+
+	/// Returns an ascending iterator over the values in `self` but not in `other`.
+	#[inline]
+	pub fn difference(self, other: Self) -> impl FusedIterator<Item = usize> + Clone + 'data {
+		let chunks = self.data.len();
+
+		Combine::new(
+			move |offset| self.data[offset] & !other.chunk(offset),
+			chunks,
+			self.len,
+		)
+	}
+
+	/// Returns an ascending iterator over the values in `self` that were not
+	/// in the earlier snapshot `previous`.
+	#[inline]
+	pub fn added_since(self, previous: Self) -> impl FusedIterator<Item = usize> + Clone + 'data {
+		self.difference(previous)
+	}
+
+	/// Returns an ascending iterator over the values in the earlier snapshot
+	/// `previous` that are no longer in `self`.
+	#[inline]
+	pub fn removed_since(self, previous: Self) -> impl FusedIterator<Item = usize> + Clone + 'data {
+		previous.difference(self)
+	}
+
+	/// Returns an adaptor that formats every value, unlike the [`Debug`]
+	/// implementation which stops after the first few.
+	///
+	/// [`Debug`]: core::fmt::Debug
+	#[inline]
+	pub fn debug_full(self) -> impl core::fmt::Debug + 'data {
+		DebugFull(self)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'data> Borrowed<'data> {
+	/// Returns whether each of `queries` is in the set, loading each chunk
+	/// once when `queries` is sorted in ascending order.
+	#[must_use]
+	pub fn contains_sorted(self, queries: &[usize]) -> Vec<bool> {
+		let mut offset = usize::MAX;
+		let mut inner = 0;
+
+		queries
+			.iter()
+			.map(|&query| {
+				let next = crate::inner::bits_to_chunk(query);
+
+				if next != offset {
+					offset = next;
+					inner = self.chunk(next);
+				}
+
+				crate::inner::get(inner, query)
+			})
+			.collect()
+	}
+
+	/// Appends the values of the set to `dst` in ascending order, reserving
+	/// space for all of them upfront.
+	#[inline]
+	pub fn extend_vec(self, dst: &mut Vec<usize>) {
+		dst.reserve(self.len);
+		dst.extend(self.ascending());
+	}
+
+	/// Packs the set into bytes, most significant bit first within each
+	/// byte, so value `0` is the top bit of the first byte. Trailing zero
+	/// bytes are omitted.
+	#[must_use]
+	pub fn to_msb_bytes(self) -> Vec<u8> {
+		let mut bytes: Vec<u8> = self
+			.trimmed()
+			.iter()
+			.flat_map(|inner| inner.to_le_bytes())
+			.map(u8::reverse_bits)
+			.collect();
+
+		let used = bytes
+			.iter()
+			.rposition(|&byte| byte != 0)
+			.map_or(0, |last| last + 1);
+
+		bytes.truncate(used);
+		bytes
+	}
+
+	/// Writes the values in either set into `dst`, reusing its buffer.
+	#[inline]
+	pub fn union_into(self, other: Self, dst: &mut Owned) {
+		let chunks = self.data.len().max(other.data.len());
+
+		dst.fill_chunks(chunks, |offset| self.chunk(offset) | other.chunk(offset));
+	}
+
+	/// Writes the values in both sets into `dst`, reusing its buffer.
+	#[inline]
+	pub fn intersection_into(self, other: Self, dst: &mut Owned) {
+		let chunks = self.data.len().min(other.data.len());
+
+		dst.fill_chunks(chunks, |offset| self.data[offset] & other.data[offset]);
+	}
+
+	/// Writes the values in `self` but not in `other` into `dst`, reusing its buffer.
+	#[inline]
+	pub fn difference_into(self, other: Self, dst: &mut Owned) {
+		let chunks = self.data.len();
+
+		dst.fill_chunks(chunks, |offset| self.data[offset] & !other.chunk(offset));
+	}
+
+	/// Writes the values in exactly one of the sets into `dst`, reusing its buffer.
+	#[inline]
+	pub fn symmetric_difference_into(self, other: Self, dst: &mut Owned) {
+		let chunks = self.data.len().max(other.data.len());
+
+		dst.fill_chunks(chunks, |offset| self.chunk(offset) ^ other.chunk(offset));
+	}
 }
 
 impl<'data> PartialEq for Borrowed<'data> {
 	#[inline]
 	fn eq(&self, other: &Self) -> bool {
-		(self.len == other.len) && self.ascending().eq(other.ascending())
+		let (short, long) = if self.data.len() <= other.data.len() {
+			(self.data, other.data)
+		} else {
+			(other.data, self.data)
+		};
+
+		let (head, tail) = long.split_at(short.len());
+
+		(self.len == other.len) && short == head && tail.iter().all(|&inner| inner == 0)
 	}
 }
 
@@ -81,6 +1182,9 @@ impl<'data> PartialOrd for Borrowed<'data> {
 	}
 }
 
+/// Sets are ordered lexicographically by their ascending values, the same as
+/// `BTreeSet`, so `{0, 2}` is less than `{1}`. See [`Borrowed::subset_order`]
+/// for ordering by inclusion.
 impl<'data> Ord for Borrowed<'data> {
 	#[inline]
 	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
@@ -100,6 +1204,25 @@ impl<'data> IntoIterator for Borrowed<'data> {
 
 impl<'data> core::fmt::Debug for Borrowed<'data> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		self.ascending().fmt(f)
+		const LIMIT: usize = 16;
+
+		let mut list = f.debug_list();
+
+		list.entries(self.ascending().take(LIMIT));
+
+		if self.len > LIMIT {
+			list.entry(&format_args!("…"));
+			list.entry(&format_args!("({} total)", self.len));
+		}
+
+		list.finish()
+	}
+}
+
+struct DebugFull<'data>(Borrowed<'data>);
+
+impl core::fmt::Debug for DebugFull<'_> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_list().entries(self.0.ascending()).finish()
 	}
 }
@@ -0,0 +1,97 @@
+use alloc::vec::Vec;
+
+use crate::{inner::Inner, owned::Owned};
+
+/// A builder of a set that grows its buffer with amortized doubling.
+#[derive(Clone, Default, Debug)]
+pub struct Builder {
+	data: Vec<Inner>,
+	len: usize,
+}
+
+impl Builder {
+	/// Returns a new builder with no values.
+	#[inline]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			data: Vec::new(),
+			len: 0,
+		}
+	}
+
+	/// Returns the number of values inserted so far.
+	#[inline]
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns whether no values were inserted so far.
+	#[inline]
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	fn grow_chunks(&mut self, chunks: usize) {
+		if chunks > self.data.len() {
+			self.data.resize(chunks, 0);
+		}
+	}
+
+	/// Inserts the given index and returns the previous state.
+	#[inline]
+	pub fn insert(&mut self, value: usize) -> bool {
+		let offset = crate::inner::bits_to_chunk(value);
+
+		self.grow_chunks(offset + 1);
+
+		let inner = &mut self.data[offset];
+		let previous = crate::inner::get(*inner, value);
+
+		*inner |= crate::inner::mask(value);
+		self.len += usize::from(!previous);
+
+		previous
+	}
+
+	/// Inserts the given exclusive range.
+	pub fn insert_all(&mut self, start: usize, end: usize) {
+		let chunks = crate::inner::range_chunks(start, end);
+
+		self.grow_chunks(chunks.end);
+
+		for offset in chunks {
+			let inner = &mut self.data[offset];
+			let mask = crate::inner::range_mask(start, end, offset);
+
+			self.len += (mask & !*inner).count_ones() as usize;
+
+			*inner |= mask;
+		}
+	}
+
+	/// Returns the built set, shrunk to fit its values.
+	#[inline]
+	#[must_use]
+	pub fn build(mut self) -> Owned {
+		let used = self.data.iter().rposition(|&inner| inner != 0);
+
+		self.data.truncate(used.map_or(0, |offset| offset + 1));
+
+		Owned {
+			data: self.data.into_boxed_slice(),
+			len: self.len,
+		}
+	}
+}
+
+impl Extend<usize> for Builder {
+	#[inline]
+	fn extend<T: IntoIterator<Item = usize>>(&mut self, iter: T) {
+		for value in iter {
+			self.insert(value);
+		}
+	}
+}
@@ -0,0 +1,71 @@
+use core::iter::FusedIterator;
+
+use crate::inner::{Inner, Iter};
+
+/// The sets that a value yielded by [`Slice::merge_join`](crate::Slice::merge_join) is in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MergeSide {
+	/// The value is only in the left set.
+	Left,
+	/// The value is only in the right set.
+	Right,
+	/// The value is in both sets.
+	Both,
+}
+
+/// An ascending iterator over the values of chunks built lazily by a closure,
+/// such as the intersection of two sets.
+#[derive(Clone)]
+pub struct Combine<F> {
+	source: F,
+	end: usize,
+	chunks: usize,
+	upper: usize,
+	cached: Iter,
+}
+
+impl<F: Fn(usize) -> Inner> Combine<F> {
+	/// Returns a new iterator over the first `chunks` chunks of `source`,
+	/// which must hold at most `upper` values.
+	#[inline]
+	pub const fn new(source: F, chunks: usize, upper: usize) -> Self {
+		Self {
+			source,
+			end: 0,
+			chunks,
+			upper,
+			cached: Iter::new(0),
+		}
+	}
+}
+
+impl<F: Fn(usize) -> Inner> Iterator for Combine<F> {
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.cached.len() == 0 {
+			if self.end == self.chunks {
+				self.upper = 0;
+
+				return None;
+			}
+
+			self.cached = Iter::new((self.source)(self.end));
+			self.end += 1;
+		}
+
+		self.upper = self.upper.saturating_sub(1);
+
+		self.cached
+			.next()
+			.map(|index| crate::inner::chunk_to_bits(self.end - 1) + usize::from(index))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.cached.len(), Some(self.upper))
+	}
+}
+
+impl<F: Fn(usize) -> Inner> FusedIterator for Combine<F> {}
@@ -32,9 +32,48 @@ impl<'data> Descending<'data> {
 		}
 	}
 
+	/// Returns a new iterator over the set values, resuming at chunk `offset`
+	/// which holds the values `first`.
+	///
+	/// # Safety
+	///
+	/// The caller ensures that there are at least `len` bits set to 1
+	/// within `first` and the chunks of `data` before `offset`.
+	#[inline]
+	pub(crate) const unsafe fn with_offset(
+		data: &'data [Inner],
+		offset: usize,
+		first: Inner,
+		remaining: usize,
+	) -> Self {
+		Self {
+			start: data.as_ptr(),
+			end: offset,
+			len: remaining,
+			cached: Iter::new(first),
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Limits the iterator to at most `len` more values.
+	#[inline]
+	pub(crate) const fn truncated(mut self, len: usize) -> Self {
+		if len < self.len {
+			self.len = len;
+		}
+
+		self
+	}
+
 	#[inline]
 	unsafe fn find_non_zero(&mut self) {
 		loop {
+			#[cfg(debug_assertions)]
+			assert!(
+				self.end != 0,
+				"iterator should stay within its chunks, is `len` miscounted?"
+			);
+
 			self.end -= 1;
 
 			let inner = unsafe { self.start.add(self.end).read() };
@@ -67,6 +106,33 @@ impl<'data> Iterator for Descending<'data> {
 			.map(|index| crate::inner::chunk_to_bits(self.end) + index)
 	}
 
+	// `try_fold` is not overridden, as that needs the unstable `Try` trait.
+	#[inline]
+	fn fold<B, F: FnMut(B, Self::Item) -> B>(mut self, init: B, mut f: F) -> B {
+		let mut accum = init;
+
+		while self.len != 0 {
+			if self.cached.len() == 0 {
+				// SAFETY: We have at least 1 bit left and the current chunk does
+				// not contain it.
+				unsafe { self.find_non_zero() };
+			}
+
+			let base = crate::inner::chunk_to_bits(self.end);
+			let cached = core::mem::replace(&mut self.cached, Iter::new(0));
+			let count = cached.len().min(self.len);
+
+			accum = cached
+				.rev()
+				.take(count)
+				.fold(accum, |accum, index| f(accum, base + usize::from(index)));
+
+			self.len -= count;
+		}
+
+		accum
+	}
+
 	#[inline]
 	fn size_hint(&self) -> (usize, Option<usize>) {
 		(self.len, Some(self.len))
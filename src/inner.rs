@@ -1,32 +1,171 @@
 use core::iter::FusedIterator;
 
+/// The word type storing the bits of a set, least significant bit first.
 pub type Inner = u64;
 
 #[allow(clippy::cast_possible_truncation)]
 const BITS_U8: u8 = Inner::BITS as u8;
 const BITS_USIZE: usize = Inner::BITS as usize;
 
+/// Returns the index of the word holding the value `index`.
 #[inline]
+#[must_use]
 pub const fn bits_to_chunk(index: usize) -> usize {
 	index / BITS_USIZE
 }
 
+/// Returns the first value held by the word at `index`.
 #[inline]
+#[must_use]
 pub const fn chunk_to_bits(index: usize) -> usize {
 	index * BITS_USIZE
 }
 
+#[cfg(feature = "alloc")]
+#[allow(clippy::cast_possible_truncation)]
+#[inline]
+pub const fn shift(bit: usize) -> u32 {
+	(bit % BITS_USIZE) as u32
+}
+
 #[inline]
 pub const fn mask(bit: usize) -> Inner {
 	1 << (bit % BITS_USIZE)
 }
 
+#[inline]
+pub const fn mask_below(bit: usize) -> Inner {
+	mask(bit) - 1
+}
+
+#[inline]
+pub const fn range_chunks(start: usize, end: usize) -> core::ops::Range<usize> {
+	if start < end {
+		bits_to_chunk(start)..bits_to_chunk(end - 1) + 1
+	} else {
+		0..0
+	}
+}
+
+#[inline]
+pub const fn range_mask(start: usize, end: usize, offset: usize) -> Inner {
+	let lower = if offset == bits_to_chunk(start) {
+		!mask_below(start)
+	} else {
+		Inner::MAX
+	};
+
+	let upper = if offset == bits_to_chunk(end) {
+		mask_below(end)
+	} else {
+		Inner::MAX
+	};
+
+	lower & upper
+}
+
 #[inline]
 pub const fn get(inner: Inner, bit: usize) -> bool {
 	inner & mask(bit) != 0
 }
 
+#[cfg(not(feature = "simd"))]
+#[inline]
+pub fn count_ones(data: &[Inner]) -> usize {
+	data.iter().map(|inner| inner.count_ones() as usize).sum()
+}
+
+#[cfg(feature = "simd")]
+#[allow(clippy::cast_possible_truncation)]
+#[inline]
+pub fn count_ones(data: &[Inner]) -> usize {
+	use core::simd::{num::SimdUint, Simd};
+
+	const LANES: usize = 8;
+
+	let (prefix, middle, suffix) = data.as_simd::<LANES>();
+	let total = middle
+		.iter()
+		.fold(Simd::<Inner, LANES>::splat(0), |total, &chunk| {
+			total + chunk.count_ones()
+		});
+
+	prefix
+		.iter()
+		.chain(suffix)
+		.fold(total.reduce_sum() as usize, |total, inner| {
+			total + inner.count_ones() as usize
+		})
+}
+
+#[cfg(not(feature = "simd"))]
+#[inline]
+fn count_zipped<F>(left: &[Inner], right: &[Inner], scalar: F) -> usize
+where
+	F: Fn(Inner, Inner) -> Inner,
+{
+	left.iter()
+		.zip(right)
+		.map(|(&lhs, &rhs)| scalar(lhs, rhs).count_ones() as usize)
+		.sum()
+}
+
+#[cfg(feature = "simd")]
+#[allow(clippy::cast_possible_truncation)]
+#[inline]
+fn count_zipped<F, V>(left: &[Inner], right: &[Inner], scalar: F, vector: V) -> usize
+where
+	F: Fn(Inner, Inner) -> Inner,
+	V: Fn(core::simd::Simd<Inner, 8>, core::simd::Simd<Inner, 8>) -> core::simd::Simd<Inner, 8>,
+{
+	use core::simd::{num::SimdUint, Simd};
+
+	let shared = left.len().min(right.len());
+	let (left, right) = (&left[..shared], &right[..shared]);
+	let mut total = Simd::splat(0);
+
+	let left_lanes = left.chunks_exact(8);
+	let right_lanes = right.chunks_exact(8);
+	let remainder = left_lanes.remainder().iter().zip(right_lanes.remainder());
+
+	for (lhs, rhs) in left_lanes.zip(right_lanes) {
+		total += vector(Simd::from_slice(lhs), Simd::from_slice(rhs)).count_ones();
+	}
+
+	remainder.fold(total.reduce_sum() as usize, |total, (&lhs, &rhs)| {
+		total + scalar(lhs, rhs).count_ones() as usize
+	})
+}
+
+/// Returns the number of bits set in either `left` or `right`.
+#[inline]
+pub fn union_count(left: &[Inner], right: &[Inner]) -> usize {
+	let shared = left.len().min(right.len());
+
+	#[cfg(not(feature = "simd"))]
+	let both = count_zipped(left, right, |lhs, rhs| lhs | rhs);
+
+	#[cfg(feature = "simd")]
+	let both = count_zipped(left, right, |lhs, rhs| lhs | rhs, |lhs, rhs| lhs | rhs);
+
+	both + count_ones(&left[shared..]) + count_ones(&right[shared..])
+}
+
+/// Returns the number of bits set in both `left` and `right`.
+#[inline]
+pub fn intersection_count(left: &[Inner], right: &[Inner]) -> usize {
+	#[cfg(not(feature = "simd"))]
+	let both = count_zipped(left, right, |lhs, rhs| lhs & rhs);
+
+	#[cfg(feature = "simd")]
+	let both = count_zipped(left, right, |lhs, rhs| lhs & rhs, |lhs, rhs| lhs & rhs);
+
+	both
+}
+
+/// An iterator over the positions of set bits within a chunk.
 #[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct Iter {
 	inner: Inner,
 	remaining: u8,
@@ -41,6 +180,24 @@ impl Iter {
 			remaining: inner.count_ones() as u8,
 		}
 	}
+
+	/// Removes the lowest run of set bits, returning its start and length.
+	#[allow(clippy::cast_possible_truncation)]
+	#[inline]
+	pub fn next_run(&mut self) -> Option<(u8, u8)> {
+		if self.remaining == 0 {
+			return None;
+		}
+
+		let start = self.inner.trailing_zeros();
+		let length = (!(self.inner >> start)).trailing_zeros();
+		let ones = Inner::MAX >> (Inner::BITS - length);
+
+		self.inner &= !(ones << start);
+		self.remaining -= length as u8;
+
+		Some((start as u8, length as u8))
+	}
 }
 
 impl Iterator for Iter {
@@ -94,6 +251,12 @@ impl ExactSizeIterator for Iter {}
 
 impl FusedIterator for Iter {}
 
+impl core::fmt::Debug for Iter {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_list().entries(self.clone()).finish()
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use alloc::vec::Vec;
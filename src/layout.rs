@@ -0,0 +1,27 @@
+//! Helpers for working with the words backing a set, such as those
+//! returned by `as_words`.
+//!
+//! The layout is stable: the value `v` is stored in bit `v % Inner::BITS`
+//! of word `v / Inner::BITS`, with bit 0 being the least significant.
+//! Words are plain [`Inner`] integers in native byte order, laid out
+//! contiguously in ascending order.
+
+pub use crate::inner::{bits_to_chunk, chunk_to_bits, Inner};
+
+/// The order in which values map to the bits of a word.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BitOrder {
+	/// The smallest value of a word is stored in its least significant bit.
+	Lsb0,
+}
+
+/// A description of the words backing a set.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LayoutInfo {
+	/// The number of bits in each word.
+	pub chunk_bits: u32,
+	/// The order of values within each word.
+	pub bit_order: BitOrder,
+	/// The number of words.
+	pub chunk_count: usize,
+}
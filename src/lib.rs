@@ -1,13 +1,39 @@
 #![no_std]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 
+#[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 
 mod borrowed;
+#[cfg(feature = "alloc")]
+mod builder;
+mod combine;
 mod inner;
+#[cfg(feature = "alloc")]
 mod owned;
+mod runs;
+#[cfg(feature = "alloc")]
+mod shared;
 
 pub mod ascending;
 pub mod descending;
+pub mod layout;
 
-pub use borrowed::Borrowed as Slice;
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod macros;
+
+#[cfg(feature = "rayon")]
+pub mod parallel;
+
+pub use borrowed::{Borrowed as Slice, SubsetResult};
+#[cfg(feature = "alloc")]
+pub use builder::Builder as SetBuilder;
+pub use combine::MergeSide;
+pub use inner::Iter as Bits;
+#[cfg(feature = "alloc")]
 pub use owned::Owned as Set;
+#[cfg(feature = "sync")]
+pub use shared::SyncSharedSet;
+#[cfg(feature = "alloc")]
+pub use shared::{Shared, SharedSet};
@@ -0,0 +1,62 @@
+use core::ops::Range;
+
+use crate::owned::Owned;
+
+/// Returns a new [`Set`](crate::Set) holding the listed values and exclusive
+/// ranges, such as `set![1, 3, 5..9]`.
+///
+/// The set is allocated once to fit the largest value and ranges are filled
+/// chunk by chunk.
+#[macro_export]
+macro_rules! set {
+	() => {
+		$crate::Set::new()
+	};
+	($($item:expr),+ $(,)?) => {
+		$crate::macros::from_bounds(&[$($crate::macros::Bounds::bounds($item)),+])
+	};
+}
+
+/// A value or range accepted by [`set!`](crate::set).
+pub trait Bounds {
+	/// Returns the exclusive range of values covered.
+	fn bounds(self) -> (usize, usize);
+}
+
+impl Bounds for usize {
+	#[inline]
+	fn bounds(self) -> (usize, usize) {
+		let end = self
+			.checked_add(1)
+			.expect("`value` should be below `usize::MAX`");
+
+		(self, end)
+	}
+}
+
+impl Bounds for Range<usize> {
+	#[inline]
+	fn bounds(self) -> (usize, usize) {
+		(self.start, self.end)
+	}
+}
+
+/// Returns a new set holding every exclusive range in `bounds`.
+#[must_use]
+pub fn from_bounds(bounds: &[(usize, usize)]) -> Owned {
+	let maximum = bounds
+		.iter()
+		.filter(|&&(start, end)| start < end)
+		.map(|&(_, end)| end)
+		.max()
+		.unwrap_or(0);
+
+	let mut set = Owned::with_maximum(maximum);
+
+	for &(start, end) in bounds {
+		// SAFETY: The set was allocated to fit the largest range.
+		unsafe { set.insert_all(start, end).unwrap_unchecked() };
+	}
+
+	set
+}
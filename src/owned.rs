@@ -1,14 +1,35 @@
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{
+	boxed::Box,
+	collections::{BTreeSet, TryReserveError},
+	vec::Vec,
+};
+use core::{
+	iter::FusedIterator,
+	ops::{Bound, RangeBounds},
+};
 
-use crate::{ascending::Ascending, borrowed::Borrowed, descending::Descending, inner::Inner};
+use crate::{
+	ascending::Ascending,
+	borrowed::Borrowed,
+	descending::Descending,
+	inner::{Inner, Iter},
+};
 
 /// An owned set of natural numbers.
 pub struct Owned {
-	data: Box<[Inner]>,
-	len: usize,
+	pub(crate) data: Box<[Inner]>,
+	pub(crate) len: usize,
 }
 
 impl Owned {
+	/// A set that accomodates no values.
+	pub const EMPTY: Self = Self::new();
+
+	/// The most chunks that [`Owned::grow_maximum_saturating`] allocates,
+	/// which is 128 MiB of words. The cap is fixed; use
+	/// [`Owned::grow_maximum_capped`] to choose another one.
+	pub const MAX_CHUNKS: usize = 1 << 24;
+
 	/// Returns a new instance of the set that accomodates no values.
 	#[inline]
 	#[must_use]
@@ -24,26 +45,241 @@ impl Owned {
 	#[inline]
 	#[must_use]
 	pub fn with_maximum(maximum: usize) -> Self {
-		let data = alloc::vec![0; maximum].into_boxed_slice();
+		let data = alloc::vec![0; crate::inner::range_chunks(0, maximum).end].into_boxed_slice();
 
 		Self { data, len: 0 }
 	}
 
+	/// Returns a new instance of the set holding every value in `0..maximum`.
+	#[inline]
+	#[must_use]
+	pub fn full(maximum: usize) -> Self {
+		let data = crate::inner::range_chunks(0, maximum)
+			.map(|offset| crate::inner::range_mask(0, maximum, offset))
+			.collect();
+
+		Self { data, len: maximum }
+	}
+
+	/// Returns a new instance of the set holding the Z-order code of each
+	/// point, which interleaves the bits of `x` and `y` with `x` lowest.
+	///
+	/// # Panics
+	///
+	/// Panics if a code does not fit in a `usize`.
+	#[cfg(feature = "morton")]
+	#[must_use]
+	pub fn from_morton_2d(points: &[(u32, u32)]) -> Self {
+		fn spread(value: u32) -> u64 {
+			let mut value = u64::from(value);
+
+			value = (value | value << 16) & 0x0000_FFFF_0000_FFFF;
+			value = (value | value << 8) & 0x00FF_00FF_00FF_00FF;
+			value = (value | value << 4) & 0x0F0F_0F0F_0F0F_0F0F;
+			value = (value | value << 2) & 0x3333_3333_3333_3333;
+			(value | value << 1) & 0x5555_5555_5555_5555
+		}
+
+		let code = |&(x, y): &(u32, u32)| {
+			usize::try_from(spread(x) | spread(y) << 1).expect("code should fit in a `usize`")
+		};
+
+		let mut set = Self::new();
+
+		if let Some(last) = points.iter().map(code).max() {
+			set.reserve_exact_chunks(crate::inner::bits_to_chunk(last) + 1);
+		}
+
+		for point in points {
+			set.grow_insert(code(point));
+		}
+
+		set
+	}
+
+	/// Returns a new instance of the set with the values of `iter`, allocated
+	/// upfront to accomodate values up to `max_hint`.
+	#[inline]
+	pub fn from_iter_with_hint<T: IntoIterator<Item = usize>>(iter: T, max_hint: usize) -> Self {
+		let mut set = Self::new();
+
+		set.reserve_exact_chunks(crate::inner::bits_to_chunk(max_hint) + 1);
+		set.extend(iter);
+
+		set
+	}
+
+	/// Returns a new instance of the set holding each value of `range` for
+	/// which `predicate` returns `true`, calling it once per value in order.
+	#[inline]
+	pub fn from_fn<F: FnMut(usize) -> bool>(
+		range: core::ops::Range<usize>,
+		mut predicate: F,
+	) -> Self {
+		let mut data = alloc::vec![0; crate::inner::range_chunks(0, range.end).end];
+		let mut len = 0;
+
+		for value in range.filter(|&value| predicate(value)) {
+			data[crate::inner::bits_to_chunk(value)] |= crate::inner::mask(value);
+			len += 1;
+		}
+
+		Self {
+			data: data.into_boxed_slice(),
+			len,
+		}
+	}
+
+	/// Returns a new instance of the set with the values of every iterator,
+	/// filling one chunk at a time.
+	///
+	/// Each iterator should yield its values in ascending order. Unsorted
+	/// values are still inserted correctly, but fill chunks less efficiently.
+	pub fn from_sorted_merge<I: Iterator<Item = usize>>(iters: &mut [I]) -> Self {
+		let mut set = Self::new();
+
+		for iter in iters {
+			let mut offset = 0;
+			let mut word = 0;
+
+			for value in iter {
+				let next = crate::inner::bits_to_chunk(value);
+
+				if next != offset && word != 0 {
+					set.grow_chunks(offset + 1);
+					set.union_chunk(offset, word);
+
+					word = 0;
+				}
+
+				offset = next;
+				word |= crate::inner::mask(value);
+			}
+
+			if word != 0 {
+				set.grow_chunks(offset + 1);
+				set.union_chunk(offset, word);
+			}
+		}
+
+		set
+	}
+
+	/// Returns a new instance of the set with each value of `iter` whose flag is `true`.
+	#[inline]
+	pub fn from_pairs<T: IntoIterator<Item = (usize, bool)>>(iter: T) -> Self {
+		let mut set = Self::new();
+
+		for (value, present) in iter {
+			if present {
+				set.grow_insert(value);
+			}
+		}
+
+		set
+	}
+
+	/// Returns a new instance of the set backed by `data`, counting its values.
+	#[inline]
+	#[must_use]
+	pub fn from_boxed_words(data: Box<[Inner]>) -> Self {
+		let len = crate::inner::count_ones(&data);
+
+		Self { data, len }
+	}
+
+	/// Returns a new instance of the set holding `base + i` for every bit `i`
+	/// set in `bits`, allocated to accomodate values up to `base + 63`.
+	#[inline]
+	#[must_use]
+	pub fn from_bits(bits: Inner, base: usize) -> Self {
+		let mut set = Self::with_maximum(base.saturating_add(Inner::BITS as usize));
+
+		set.union_at([bits], base);
+		set
+	}
+
+	/// Returns a new instance of the set backed by the chunks of `iter` in
+	/// order, counting its values.
+	#[inline]
+	pub fn from_chunks<I: IntoIterator<Item = Inner>>(iter: I) -> Self {
+		Self::from_boxed_words(iter.into_iter().collect())
+	}
+
+	/// Consumes the set and returns the chunks backing it.
+	#[inline]
+	#[must_use]
+	pub fn into_words(self) -> Box<[Inner]> {
+		self.data
+	}
+
 	/// Returns a lightweight borrow of the set.
 	#[inline]
 	#[must_use]
-	pub const fn as_slice(&self) -> Borrowed {
+	pub const fn as_slice(&self) -> Borrowed<'_> {
 		// SAFETY: `len` correctly tracks how many set bits exist.
 		unsafe { Borrowed::new(&self.data, self.len) }
 	}
 
-	/// Returns the largest value the set can store.
+	/// Returns whether both sets hold the same values in the same number of
+	/// chunks, unlike `==` which ignores trailing empty chunks.
+	#[inline]
+	#[must_use]
+	pub fn eq_including_capacity(&self, other: &Self) -> bool {
+		self.data == other.data
+	}
+
+	/// Returns a borrow of the chunks in `chunk_start..chunk_end`, where value
+	/// `0` of the result is value `chunk_start * Inner::BITS` of the set.
+	///
+	/// # Panics
+	///
+	/// Panics if the chunk range is out of bounds.
+	#[inline]
+	#[must_use]
+	pub fn slice_range(&self, chunk_start: usize, chunk_end: usize) -> Borrowed<'_> {
+		Borrowed::from_words(&self.data[chunk_start..chunk_end])
+	}
+
+	/// Returns the chunks backing the set.
+	#[inline]
+	#[must_use]
+	pub const fn as_words(&self) -> &[Inner] {
+		self.as_slice().as_words()
+	}
+
+	/// Returns the number of values the set can store without growing, so
+	/// that exactly the values `0..maximum` fit.
+	///
+	/// See [`Borrowed::maximum`] for how it relates to the backing words.
+	/// It is the same as [`Owned::bit_capacity`].
 	#[inline]
 	#[must_use]
 	pub const fn maximum(&self) -> usize {
 		self.as_slice().maximum()
 	}
 
+	/// Returns the number of bits in the backing words.
+	#[inline]
+	#[must_use]
+	pub const fn bit_capacity(&self) -> usize {
+		crate::inner::chunk_to_bits(self.data.len())
+	}
+
+	/// Returns the number of backing words.
+	#[inline]
+	#[must_use]
+	pub const fn chunk_capacity(&self) -> usize {
+		self.data.len()
+	}
+
+	/// Returns the number of values the set could store once shrunk to fit.
+	#[inline]
+	#[must_use]
+	pub fn normalized_maximum(&self) -> usize {
+		self.as_slice().normalized_maximum()
+	}
+
 	/// Returns the number of values in the set.
 	#[inline]
 	#[must_use]
@@ -51,6 +287,13 @@ impl Owned {
 		self.as_slice().len()
 	}
 
+	/// Returns whether the stored length matches the number of set bits.
+	#[inline]
+	#[must_use]
+	pub fn validate(&self) -> bool {
+		self.as_slice().validate()
+	}
+
 	/// Returns whether the set contains any value.
 	#[inline]
 	#[must_use]
@@ -65,18 +308,117 @@ impl Owned {
 		self.as_slice().contains(value)
 	}
 
+	/// Returns whether the set contains the given value, or `None` if it is
+	/// beyond what the set can store.
+	#[inline]
+	#[must_use]
+	pub const fn get(&self, value: usize) -> Option<bool> {
+		self.as_slice().get(value)
+	}
+
+	/// Returns the only value stored if the set holds exactly one.
+	#[inline]
+	#[must_use]
+	pub fn as_singleton(&self) -> Option<usize> {
+		self.as_slice().as_singleton()
+	}
+
+	/// Returns the number of values stored below `value`.
+	#[inline]
+	#[must_use]
+	pub fn count_below(&self, value: usize) -> usize {
+		self.as_slice().count_below(value)
+	}
+
+	/// Returns the number of values stored above `value`.
+	#[inline]
+	#[must_use]
+	pub fn count_above(&self, value: usize) -> usize {
+		self.as_slice().count_above(value)
+	}
+
 	/// Returns an ascending iterator over the stored values.
 	#[inline]
-	pub const fn ascending(&self) -> Ascending {
+	pub const fn ascending(&self) -> Ascending<'_> {
 		self.as_slice().ascending()
 	}
 
+	/// Consumes the set and returns an ascending iterator over its values.
+	///
+	/// Calling this on a clone gives a snapshot that can be iterated while
+	/// the original set keeps changing, even growing.
+	#[inline]
+	pub fn ascending_owned(self) -> impl FusedIterator<Item = usize> {
+		let data = self.data;
+
+		(0..data.len()).flat_map(move |offset| {
+			let base = crate::inner::chunk_to_bits(offset);
+
+			Iter::new(data[offset]).map(move |index| base + usize::from(index))
+		})
+	}
+
+	/// Returns an ascending iterator that removes the values it yields, and
+	/// empties the set when dropped even if not fully consumed.
+	#[inline]
+	pub fn drain(&mut self) -> impl ExactSizeIterator<Item = usize> + FusedIterator + '_ {
+		Drain {
+			set: self,
+			offset: 0,
+			cached: Iter::new(0),
+		}
+	}
+
+	/// Consumes the set and returns its values in ascending order, allocated
+	/// to exactly [`Owned::len`] elements.
+	#[inline]
+	#[must_use]
+	pub fn into_sorted_boxed(self) -> Box<[usize]> {
+		let mut values = Vec::with_capacity(self.len);
+
+		values.extend(self.ascending());
+		values.into_boxed_slice()
+	}
+
 	/// Returns a descending iterator over the stored values.
 	#[inline]
-	pub const fn descending(&self) -> Descending {
+	pub const fn descending(&self) -> Descending<'_> {
 		self.as_slice().descending()
 	}
 
+	/// Returns whether every value of `self` is also in `other`.
+	#[inline]
+	#[must_use]
+	pub fn is_subset(&self, other: &Self) -> bool {
+		self.as_slice().is_subset(other.as_slice())
+	}
+
+	/// Returns whether every value of `other` is also in `self`.
+	#[inline]
+	#[must_use]
+	pub fn is_superset(&self, other: &Self) -> bool {
+		self.as_slice().is_superset(other.as_slice())
+	}
+
+	/// Returns whether the sets have no values in common.
+	#[inline]
+	#[must_use]
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.as_slice().is_disjoint(other.as_slice())
+	}
+
+	/// Hands the chunks backing the set to `handler` for arbitrary changes,
+	/// recounting the values afterward. The set is never grown.
+	///
+	/// Every bit of the chunks is a value below [`Owned::maximum`], so there
+	/// are no spare bits that changes could leave inconsistent.
+	#[inline]
+	pub fn with_words_mut<H: FnOnce(&mut [Inner])>(&mut self, handler: H) {
+		handler(&mut self.data);
+
+		self.len = crate::inner::count_ones(&self.data);
+	}
+
 	/// Removes all values from the set in bulk.
 	#[inline]
 	pub fn clear(&mut self) {
@@ -100,294 +442,4845 @@ impl Owned {
 		self.len = 0;
 	}
 
-	#[inline(never)]
-	fn with_buffer<H: Fn(&mut Vec<Inner>)>(&mut self, handler: H) {
-		let mut data = core::mem::take(&mut self.data).into_vec();
+	/// Removes all values from the set and releases its buffer.
+	#[inline]
+	pub fn clear_and_shrink(&mut self) {
+		*self = Self::new();
+	}
 
-		handler(&mut data);
+	/// Removes all values from the set and replaces its buffer with the one
+	/// of `other`, which is cleared as well.
+	#[inline]
+	pub fn reuse(&mut self, other: Self) {
+		*self = other;
 
-		self.data = data.into_boxed_slice();
+		self.clear();
 	}
 
-	/// Grows the set to accomodate at least the values `0..maximum`.
-	#[inline]
-	pub fn grow_maximum(&mut self, maximum: usize) {
-		let offset = crate::inner::bits_to_chunk(maximum);
+	/// Makes the set hold exactly the values in the given exclusive range,
+	/// growing it to fit them.
+	pub fn reset_to_range(&mut self, start: usize, end: usize) {
+		let chunks = crate::inner::range_chunks(start, end);
 
-		if offset >= self.data.len() {
-			self.with_buffer(move |data| {
-				data.reserve(offset + 1 - data.capacity());
-				data.resize(data.capacity(), 0);
-			});
+		self.grow_chunks(chunks.end);
+
+		for (offset, inner) in self.data.iter_mut().enumerate() {
+			*inner = if chunks.contains(&offset) {
+				crate::inner::range_mask(start, end, offset)
+			} else {
+				0
+			};
 		}
+
+		self.len = end.saturating_sub(start);
 	}
 
-	/// Shrinks the set to accomodate at most the values it has.
-	#[inline]
-	pub fn shrink_to_fit(&mut self) {
-		let maximum = self
-			.data
-			.iter()
-			.rposition(|&value| value != 0)
-			.map_or(0, |index| index + 1);
+	/// Removes the values in the given exclusive range from the set, keeping its capacity.
+	pub fn clear_range(&mut self, start: usize, end: usize) {
+		let chunks = crate::inner::range_chunks(start, end);
+		let mut removed = 0;
 
-		self.with_buffer(move |data| data.truncate(maximum));
+		for offset in chunks.start..chunks.end.min(self.data.len()) {
+			let mask = crate::inner::range_mask(start, end, offset);
+			let inner = &mut self.data[offset];
+
+			removed += (*inner & mask).count_ones() as usize;
+
+			*inner &= !mask;
+		}
+
+		self.len -= removed;
 	}
 
-	/// Inserts the given index into the set and returns the previous state.
-	#[inline]
-	pub fn insert(&mut self, value: usize) -> Option<bool> {
-		let offset = crate::inner::bits_to_chunk(value);
+	/// Retains only the values in `range` for which `f` returns `true`,
+	/// leaving the values outside of it untouched.
+	pub fn retain_range<F: FnMut(usize) -> bool>(
+		&mut self,
+		range: core::ops::Range<usize>,
+		mut f: F,
+	) {
+		let chunks = crate::inner::range_chunks(range.start, range.end);
 
-		self.data.get_mut(offset).map(|inner| {
-			if crate::inner::get(*inner, value) {
-				true
-			} else {
-				*inner |= crate::inner::mask(value);
+		for offset in chunks.start..chunks.end.min(self.data.len()) {
+			let window = crate::inner::range_mask(range.start, range.end, offset);
+			let base = crate::inner::chunk_to_bits(offset);
+			let inner = &mut self.data[offset];
 
-				self.len += 1;
+			for index in Iter::new(*inner & window) {
+				let value = base + usize::from(index);
 
-				false
+				if !f(value) {
+					*inner &= !crate::inner::mask(value);
+
+					self.len -= 1;
+				}
 			}
-		})
+		}
 	}
 
-	fn insert_chunk(&mut self, offset: usize) -> Option<Inner> {
-		self.data.get_mut(offset).map(|inner| {
-			let zeros = inner.count_zeros();
+	/// Retains only the values for which `f` returns `true` and returns how
+	/// many were removed.
+	pub fn retain_collect<F: FnMut(usize) -> bool>(&mut self, mut f: F) -> usize {
+		let mut removed = 0;
 
-			self.len += usize::try_from(zeros).unwrap();
+		for (offset, inner) in self.data.iter_mut().enumerate() {
+			let base = crate::inner::chunk_to_bits(offset);
+			let mut kept = *inner;
 
-			core::mem::replace(inner, Inner::MAX)
-		})
-	}
+			for index in Iter::new(*inner) {
+				if !f(base + usize::from(index)) {
+					kept &= !crate::inner::mask(usize::from(index));
+				}
+			}
 
-	/// Inserts the given exclusive range into the set.
-	pub fn insert_all(&mut self, start: usize, end: usize) -> Option<()> {
-		let start_chunk = crate::inner::bits_to_chunk(start);
-		let naive_end = crate::inner::chunk_to_bits(start_chunk + 1).min(end);
+			removed += (*inner ^ kept).count_ones() as usize;
 
-		for value in start..naive_end {
-			self.insert(value)?;
+			*inner = kept;
 		}
 
-		let end_chunk = crate::inner::bits_to_chunk(end);
-		let naive_start = crate::inner::chunk_to_bits(end_chunk).max(start);
+		self.len -= removed;
 
-		for value in naive_start..end {
-			self.insert(value)?;
-		}
+		removed
+	}
 
-		for offset in start_chunk + 1..end_chunk {
-			self.insert_chunk(offset)?;
-		}
+	#[inline(never)]
+	fn with_buffer<R, H: FnOnce(&mut Vec<Inner>) -> R>(&mut self, handler: H) -> R {
+		let mut data = core::mem::take(&mut self.data).into_vec();
+		let result = handler(&mut data);
 
-		Some(())
+		self.data = data.into_boxed_slice();
+
+		result
 	}
 
-	/// Removes the given index from the set and returns the previous state.
-	#[inline]
-	pub fn remove(&mut self, value: usize) -> Option<bool> {
-		let offset = crate::inner::bits_to_chunk(value);
+	pub(crate) fn fill_chunks<H: Fn(usize) -> Inner>(&mut self, chunks: usize, handler: H) {
+		self.with_buffer(|data| {
+			data.clear();
+			data.extend((0..chunks).map(&handler));
+		});
 
-		self.data.get_mut(offset).map(|inner| {
-			if crate::inner::get(*inner, value) {
-				*inner &= !crate::inner::mask(value);
+		self.len = crate::inner::count_ones(&self.data);
+	}
 
-				self.len -= 1;
+	fn grow_chunks(&mut self, chunks: usize) {
+		if chunks > self.data.len() {
+			self.reserve_exact_chunks(chunks.max(self.data.len().saturating_mul(2)));
+		}
+	}
 
-				true
-			} else {
-				false
-			}
-		})
+	/// Grows the set to exactly `chunks` chunks without over-allocating,
+	/// doing nothing if it already holds that many.
+	#[inline]
+	pub fn reserve_exact_chunks(&mut self, chunks: usize) {
+		if chunks > self.data.len() {
+			self.with_buffer(move |data| {
+				data.reserve_exact(chunks - data.len());
+				data.resize(chunks, 0);
+			});
+		}
 	}
 
-	fn remove_chunk(&mut self, offset: usize) -> Option<Inner> {
-		self.data.get_mut(offset).map(|inner| {
-			let ones = inner.count_ones();
+	/// Grows the set toward accomodating the values `0..maximum` without
+	/// exceeding `cap` values, and returns whether all of them fit.
+	#[inline]
+	pub fn grow_maximum_capped(&mut self, maximum: usize, cap: usize) -> bool {
+		let needed = crate::inner::range_chunks(0, maximum).end;
 
-			self.len -= usize::try_from(ones).unwrap();
+		self.reserve_exact_chunks(needed.min(crate::inner::bits_to_chunk(cap)));
 
-			core::mem::replace(inner, Inner::MIN)
-		})
+		needed <= self.data.len()
 	}
 
-	/// Removes the given exclusive range from the set.
-	pub fn remove_all(&mut self, start: usize, end: usize) -> Option<()> {
-		let start_chunk = crate::inner::bits_to_chunk(start);
-		let naive_end = crate::inner::chunk_to_bits(start_chunk + 1).min(end);
+	/// Grows the set to accomodate at least the values `0..maximum`, in the
+	/// same way as [`Owned::grow_maximum`].
+	///
+	/// # Errors
+	///
+	/// Returns an error if the allocation fails, leaving the set unchanged.
+	pub fn try_grow_maximum(&mut self, maximum: usize) -> Result<(), TryReserveError> {
+		let chunks = crate::inner::range_chunks(0, maximum).end;
 
-		for value in start..naive_end {
-			self.remove(value)?;
+		if chunks <= self.data.len() {
+			return Ok(());
 		}
 
-		let end_chunk = crate::inner::bits_to_chunk(end);
-		let naive_start = crate::inner::chunk_to_bits(end_chunk).max(start);
+		let chunks = chunks.max(self.data.len().saturating_mul(2));
 
-		for value in naive_start..end {
-			self.remove(value)?;
-		}
+		self.with_buffer(move |data| {
+			data.try_reserve_exact(chunks - data.len())?;
+			data.resize(chunks, 0);
 
-		for offset in start_chunk + 1..end_chunk {
-			self.remove_chunk(offset)?;
-		}
+			Ok(())
+		})
+	}
 
-		Some(())
+	/// Grows the set to accomodate at least the values `0..maximum`.
+	///
+	/// The chunks at least double on every growth, so repeated calls with
+	/// increasing values are amortized. Passing the current [`Owned::maximum`]
+	/// does nothing.
+	#[inline]
+	pub fn grow_maximum(&mut self, maximum: usize) {
+		self.grow_chunks(crate::inner::range_chunks(0, maximum).end);
 	}
 
-	/// Inserts the given index into the set and returns the previous state.
+	/// Grows the set to accomodate at least the values `0..maximum` in the
+	/// same way as [`Owned::grow_maximum`], but never beyond
+	/// [`Owned::MAX_CHUNKS`] chunks. Returns whether the growth was clamped.
 	#[inline]
-	pub fn grow_insert(&mut self, value: usize) -> bool {
-		self.grow_maximum(value + 1);
+	pub fn grow_maximum_saturating(&mut self, maximum: usize) -> bool {
+		let (chunks, clamped) = saturating_chunks(self.data.len(), maximum);
 
-		unsafe { self.insert(value).unwrap_unchecked() }
+		self.reserve_exact_chunks(chunks);
+
+		clamped
 	}
 
-	/// Inserts the given exclusive range into the set.
+	/// Shrinks the set to accomodate at most the values it has.
 	#[inline]
-	pub fn grow_insert_all(&mut self, start: usize, end: usize) {
-		self.grow_maximum(end);
+	pub fn shrink_to_fit(&mut self) {
+		let maximum = self
+			.data
+			.iter()
+			.rposition(|&value| value != 0)
+			.map_or(0, |index| index + 1);
 
-		unsafe { self.insert_all(start, end).unwrap_unchecked() };
+		self.with_buffer(move |data| data.truncate(maximum));
 	}
 
-	/// Clones the data from `source` without allocating if possible.
+	/// Removes the run of consecutive values that includes `value` and returns
+	/// its exclusive range, or `None` if `value` is not in the set.
 	#[inline]
-	pub fn clone_from_slice(&mut self, source: Borrowed) {
-		self.with_buffer(move |data| {
-			data.clear();
-			data.extend_from_slice(source.data);
+	pub fn take_run(&mut self, value: usize) -> Option<(usize, usize)> {
+		let (start, end) = self.as_slice().run_bounds(value)?;
 
-			data.resize(data.capacity(), 0);
-
-			debug_assert_eq!(data.len(), data.capacity(), "buffer should not reallocate");
-		});
+		// SAFETY: The run is in the set, so it is below the maximum.
+		unsafe { self.remove_all(start, end).unwrap_unchecked() };
 
-		self.len = source.len;
+		Some((start, end))
 	}
-}
 
-impl Default for Owned {
+	/// Removes all but the `k` smallest values from the set.
 	#[inline]
-	fn default() -> Self {
-		Self::new()
+	pub fn keep_lowest(&mut self, k: usize) {
+		if let Some(cutoff) = self.as_slice().select(k) {
+			self.truncate_at(cutoff);
+		}
 	}
-}
 
-impl Clone for Owned {
+	/// Removes all but the `k` largest values from the set.
 	#[inline]
-	fn clone(&self) -> Self {
-		Self {
-			data: self.data.clone(),
-			len: self.len,
+	pub fn keep_highest(&mut self, k: usize) {
+		if k == 0 {
+			self.clear();
+		} else if let Some(cutoff) = self.as_slice().select_back(k - 1) {
+			// SAFETY: `cutoff` is in the set, so it is below the maximum.
+			unsafe { self.remove_all(0, cutoff).unwrap_unchecked() };
+		}
+	}
+
+	/// Removes all values at or above `maximum` from the set, keeping its capacity.
+	pub fn truncate_at(&mut self, maximum: usize) {
+		let offset = crate::inner::bits_to_chunk(maximum);
+
+		let Some((first, rest)) = self
+			.data
+			.get_mut(offset..)
+			.and_then(<[Inner]>::split_first_mut)
+		else {
+			return;
+		};
+
+		let kept = *first & crate::inner::mask_below(maximum);
+		let mut removed = (*first ^ kept).count_ones() as usize;
+
+		*first = kept;
+
+		for inner in rest.iter_mut().filter(|inner| **inner != 0) {
+			removed += inner.count_ones() as usize;
+
+			*inner = 0;
 		}
+
+		self.len -= removed;
 	}
 
+	/// Inserts the given index into the set and returns the previous state.
 	#[inline]
-	fn clone_from(&mut self, source: &Self) {
-		let source = source.as_slice();
+	pub fn insert(&mut self, value: usize) -> Option<bool> {
+		let offset = crate::inner::bits_to_chunk(value);
 
-		self.clone_from_slice(source);
+		self.data.get_mut(offset).map(|inner| {
+			if crate::inner::get(*inner, value) {
+				true
+			} else {
+				*inner |= crate::inner::mask(value);
+
+				self.len += 1;
+
+				false
+			}
+		})
 	}
-}
 
-impl PartialEq for Owned {
+	/// Inserts the given index into the set and returns the previous state,
+	/// treating an index beyond [`Owned::maximum`] as a logic error.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `value` is not below [`Owned::maximum`]. In
+	/// release builds such a value is ignored and `false` is returned.
 	#[inline]
-	fn eq(&self, other: &Self) -> bool {
-		self.as_slice().eq(&other.as_slice())
+	pub fn insert_strict(&mut self, value: usize) -> bool {
+		debug_assert!(
+			value < self.maximum(),
+			"`value` should be below the maximum of the set"
+		);
+
+		self.insert(value).unwrap_or(false)
 	}
-}
 
-impl Eq for Owned {}
+	fn insert_chunk(&mut self, offset: usize) -> Option<Inner> {
+		let maximum = self.maximum();
 
-impl PartialOrd for Owned {
+		self.data.get_mut(offset).map(|inner| {
+			let zeros = usize::try_from(inner.count_zeros()).unwrap();
+
+			debug_assert!(self.len + zeros <= maximum, "`len` exceeds the stored bits");
+
+			self.len += zeros;
+
+			core::mem::replace(inner, Inner::MAX)
+		})
+	}
+
+	/// Inserts the given exclusive range into the set.
+	///
+	/// Returns `None` without changing the set if the range does not fit
+	/// within [`Owned::maximum`].
+	pub fn insert_all(&mut self, start: usize, end: usize) -> Option<()> {
+		if start < end && end > self.maximum() {
+			return None;
+		}
+
+		let start_chunk = crate::inner::bits_to_chunk(start);
+		let naive_end = crate::inner::chunk_to_bits(start_chunk + 1).min(end);
+
+		for value in start..naive_end {
+			self.insert(value)?;
+		}
+
+		let end_chunk = crate::inner::bits_to_chunk(end);
+		let naive_start = crate::inner::chunk_to_bits(end_chunk).max(start);
+
+		for value in naive_start..end {
+			self.insert(value)?;
+		}
+
+		for offset in start_chunk + 1..end_chunk {
+			self.insert_chunk(offset)?;
+		}
+
+		Some(())
+	}
+
+	/// Inserts the given range into the set, with an unbounded end
+	/// stopping at [`Owned::maximum`].
 	#[inline]
-	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-		Some(self.cmp(other))
+	pub fn insert_range<R: RangeBounds<usize>>(&mut self, range: R) -> Option<()> {
+		let (start, end) = range_bounds(&range, self.maximum());
+
+		self.insert_all(start, end)
 	}
-}
 
-impl Ord for Owned {
+	/// Inserts the given exclusive range into the set and returns how many
+	/// values were not already present.
+	pub fn insert_range_count(&mut self, start: usize, end: usize) -> Option<usize> {
+		let chunks = crate::inner::range_chunks(start, end);
+		let data = self.data.get_mut(chunks.clone())?;
+		let mut added = 0;
+
+		for (offset, inner) in chunks.zip(data) {
+			let mask = crate::inner::range_mask(start, end, offset);
+
+			added += (mask & !*inner).count_ones() as usize;
+
+			*inner |= mask;
+		}
+
+		self.len += added;
+
+		Some(added)
+	}
+
+	/// Toggles the values in the given exclusive range and returns the
+	/// resulting change in the number of values.
+	#[allow(clippy::cast_possible_wrap)]
+	pub fn toggle_all_delta(&mut self, start: usize, end: usize) -> Option<isize> {
+		let chunks = crate::inner::range_chunks(start, end);
+		let data = self.data.get_mut(chunks.clone())?;
+		let mut added = 0;
+		let mut removed = 0;
+
+		for (offset, inner) in chunks.zip(data) {
+			let mask = crate::inner::range_mask(start, end, offset);
+
+			added += (mask & !*inner).count_ones() as usize;
+			removed += (mask & *inner).count_ones() as usize;
+
+			*inner ^= mask;
+		}
+
+		self.len = self.len + added - removed;
+
+		Some(added as isize - removed as isize)
+	}
+
+	/// Removes the given index from the set and returns the previous state.
 	#[inline]
-	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-		self.as_slice().cmp(&other.as_slice())
+	pub fn remove(&mut self, value: usize) -> Option<bool> {
+		let offset = crate::inner::bits_to_chunk(value);
+
+		self.data.get_mut(offset).map(|inner| {
+			if crate::inner::get(*inner, value) {
+				*inner &= !crate::inner::mask(value);
+
+				self.len -= 1;
+
+				true
+			} else {
+				false
+			}
+		})
 	}
-}
 
-impl Extend<usize> for Owned {
+	/// Removes the given index from the set and returns the previous state
+	/// along with whether the set is now empty.
 	#[inline]
-	fn extend<T: IntoIterator<Item = usize>>(&mut self, iter: T) {
-		iter.into_iter().for_each(|index| {
-			self.grow_insert(index);
-		});
+	pub fn remove_report(&mut self, value: usize) -> Option<(bool, bool)> {
+		self.remove(value).map(|removed| (removed, self.is_empty()))
 	}
-}
 
-impl FromIterator<usize> for Owned {
+	/// Removes the given index from the set, returning it if it was present.
 	#[inline]
-	fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
-		let mut set = Self::new();
+	pub fn take(&mut self, value: usize) -> Option<usize> {
+		self.remove(value).unwrap_or(false).then_some(value)
+	}
 
-		iter.into_iter().for_each(|index| {
-			set.grow_insert(index);
-		});
+	/// Removes the smallest value from the set and returns it.
+	#[inline]
+	pub fn remove_min(&mut self) -> Option<usize> {
+		let (offset, inner) = self
+			.data
+			.iter_mut()
+			.enumerate()
+			.find(|(_, inner)| **inner != 0)?;
 
-		set
+		let value = crate::inner::chunk_to_bits(offset) + inner.trailing_zeros() as usize;
+
+		*inner &= *inner - 1;
+		self.len -= 1;
+
+		Some(value)
 	}
-}
 
-impl core::fmt::Debug for Owned {
-	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		self.as_slice().fmt(f)
+	/// Removes the smallest value from the set, if any.
+	#[inline]
+	pub fn clear_lowest(&mut self) {
+		if let Some(inner) = self.data.iter_mut().find(|inner| **inner != 0) {
+			*inner &= *inner - 1;
+			self.len -= 1;
+		}
 	}
-}
 
-#[cfg(test)]
-mod test {
-	use alloc::vec::Vec;
+	/// Returns an iterator that removes and yields up to `n` of the smallest
+	/// values in ascending order. Values not yet yielded stay in the set.
+	pub fn pop_min_n(&mut self, n: usize) -> impl Iterator<Item = usize> + '_ {
+		let mut offset = 0;
+
+		core::iter::from_fn(move || {
+			if self.len == 0 {
+				return None;
+			}
 
-	use super::Owned;
+			let (position, inner) = self.data[offset..]
+				.iter_mut()
+				.enumerate()
+				.find(|(_, inner)| **inner != 0)?;
 
-	#[test]
-	fn values_iteration() {
-		let mut set = Owned::new();
-		let mut list = Vec::new();
+			offset += position;
 
-		for index in 0..10 {
-			let value = 2_usize.pow(index);
+			let value = crate::inner::chunk_to_bits(offset) + inner.trailing_zeros() as usize;
 
-			set.grow_insert(value);
-			list.push(value);
+			*inner &= *inner - 1;
+			self.len -= 1;
+
+			Some(value)
+		})
+		.take(n)
+	}
+
+	fn remove_chunk(&mut self, offset: usize) -> Option<Inner> {
+		self.data.get_mut(offset).map(|inner| {
+			let ones = usize::try_from(inner.count_ones()).unwrap();
+
+			debug_assert!(self.len >= ones, "`len` is below the stored bits");
+
+			self.len -= ones;
+
+			core::mem::replace(inner, Inner::MIN)
+		})
+	}
+
+	/// Removes the given exclusive range from the set.
+	///
+	/// Returns `None` without changing the set if the range does not fit
+	/// within [`Owned::maximum`].
+	pub fn remove_all(&mut self, start: usize, end: usize) -> Option<()> {
+		if start < end && end > self.maximum() {
+			return None;
 		}
 
-		assert!(
-			set.ascending().eq(list.iter().copied()),
-			"ascending list should be ordered"
-		);
+		let start_chunk = crate::inner::bits_to_chunk(start);
+		let naive_end = crate::inner::chunk_to_bits(start_chunk + 1).min(end);
 
-		assert!(
-			set.descending().eq(list.iter().copied().rev()),
-			"descending list should be ordered"
-		);
+		for value in start..naive_end {
+			self.remove(value)?;
+		}
+
+		let end_chunk = crate::inner::bits_to_chunk(end);
+		let naive_start = crate::inner::chunk_to_bits(end_chunk).max(start);
+
+		for value in naive_start..end {
+			self.remove(value)?;
+		}
+
+		for offset in start_chunk + 1..end_chunk {
+			self.remove_chunk(offset)?;
+		}
+
+		Some(())
 	}
 
-	#[test]
-	fn bulk_operation() {
-		let mut set = Owned::new();
+	/// Empties the chunks `chunk_start..chunk_end`, clamped to the set, and
+	/// returns how many values were removed.
+	pub fn remove_chunk_block(&mut self, chunk_start: usize, chunk_end: usize) -> usize {
+		let chunk_end = chunk_end.min(self.data.len());
+		let Some(data) = self.data.get_mut(chunk_start..chunk_end) else {
+			return 0;
+		};
 
-		set.grow_insert_all(30, 300);
+		let removed = crate::inner::count_ones(data);
 
-		assert!(
-			(30..300).eq(set.ascending()),
-			"insertion should have succeeded"
-		);
+		data.fill(0);
 
-		set.remove_all(60, 240).unwrap();
+		self.len -= removed;
 
-		assert!(
-			(30..60).chain(240..300).eq(set.ascending()),
-			"insertion should have succeeded"
-		);
+		removed
+	}
+
+	/// Removes the given exclusive range from the set and returns whether
+	/// any values were present in it.
+	pub fn remove_all_changed(&mut self, start: usize, end: usize) -> Option<bool> {
+		let chunks = crate::inner::range_chunks(start, end);
+		let data = self.data.get_mut(chunks.clone())?;
+		let mut removed = 0;
+
+		for (offset, inner) in chunks.zip(data) {
+			let mask = crate::inner::range_mask(start, end, offset);
+
+			removed += (mask & *inner).count_ones() as usize;
+
+			*inner &= !mask;
+		}
+
+		self.len -= removed;
+
+		Some(removed != 0)
+	}
+
+	/// Removes the given range from the set, with an unbounded end
+	/// stopping at [`Owned::maximum`].
+	#[inline]
+	pub fn remove_range<R: RangeBounds<usize>>(&mut self, range: R) -> Option<()> {
+		let (start, end) = range_bounds(&range, self.maximum());
+
+		self.remove_all(start, end)
+	}
+
+	/// Inserts the given index into the set and returns the previous state.
+	///
+	/// Unlike [`Owned::insert`], the set grows to fit the value, so this
+	/// never fails. [`Owned::insert_or_grow`] is the same operation.
+	///
+	/// # Panics
+	///
+	/// Panics if `value` is `usize::MAX`. Values near it abort on allocation,
+	/// see [`Owned::try_grow_maximum`] to handle that instead.
+	#[inline]
+	pub fn grow_insert(&mut self, value: usize) -> bool {
+		let maximum = value
+			.checked_add(1)
+			.expect("`value` should be below `usize::MAX`");
+
+		self.grow_maximum(maximum);
+
+		unsafe { self.insert(value).unwrap_unchecked() }
+	}
+
+	/// Inserts the given index into the set, growing it to fit, and returns
+	/// the previous state along with the number of values below it.
+	///
+	/// # Panics
+	///
+	/// Panics if `value` is `usize::MAX`.
+	#[inline]
+	pub fn insert_ranked(&mut self, value: usize) -> (bool, usize) {
+		let was_present = self.grow_insert(value);
+		let offset = crate::inner::bits_to_chunk(value);
+		let below = self.data[offset] & crate::inner::mask_below(value);
+		let rank = crate::inner::count_ones(&self.data[..offset]) + below.count_ones() as usize;
+
+		(was_present, rank)
+	}
+
+	/// Inserts the given index into the set, growing it to fit, and returns
+	/// the previous state.
+	#[inline]
+	pub fn insert_or_grow(&mut self, value: usize) -> bool {
+		self.grow_insert(value)
+	}
+
+	/// Inserts the given index into the set, growing it to fit, and returns
+	/// whether it was newly added.
+	///
+	/// This is the opposite of the state returned by [`Owned::grow_insert`].
+	#[inline]
+	pub fn ensure(&mut self, value: usize) -> bool {
+		!self.grow_insert(value)
+	}
+
+	/// Inserts the given exclusive range into the set.
+	#[inline]
+	pub fn grow_insert_all(&mut self, start: usize, end: usize) {
+		self.grow_maximum(end);
+
+		unsafe { self.insert_all(start, end).unwrap_unchecked() };
+	}
+
+	/// Inserts the given range into the set, growing it to fit.
+	///
+	/// # Panics
+	///
+	/// Panics if the range has no end, as there is no maximum to grow to.
+	#[inline]
+	pub fn grow_insert_range<R: RangeBounds<usize>>(&mut self, range: R) {
+		assert!(
+			!matches!(range.end_bound(), Bound::Unbounded),
+			"`range` should have an end to grow to"
+		);
+
+		let (start, end) = range_bounds(&range, self.maximum());
+
+		self.grow_insert_all(start, end);
+	}
+
+	/// Inserts the given values into the set, growing it at most once, and
+	/// returns how many were not already present.
+	pub fn insert_slice(&mut self, values: &[usize]) -> usize {
+		let Some(&last) = values.iter().max() else {
+			return 0;
+		};
+
+		self.grow_chunks(crate::inner::bits_to_chunk(last) + 1);
+
+		values
+			.iter()
+			.filter(|&&value| {
+				// SAFETY: The set was grown to fit the largest value.
+				!unsafe { self.insert(value).unwrap_unchecked() }
+			})
+			.count()
+	}
+
+	fn union_chunk(&mut self, offset: usize, value: Inner) {
+		let inner = &mut self.data[offset];
+
+		self.len += (value & !*inner).count_ones() as usize;
+
+		*inner |= value;
+	}
+
+	/// Keeps only the values of the chunk at `chunk_index` whose bits are set
+	/// in `mask`, doing nothing if the chunk is out of bounds.
+	#[inline]
+	pub fn retain_mask_chunk(&mut self, chunk_index: usize, mask: Inner) {
+		if let Some(inner) = self.data.get_mut(chunk_index) {
+			self.len -= (*inner & !mask).count_ones() as usize;
+
+			*inner &= mask;
+		}
+	}
+
+	/// Inserts the values of `other` into the set, growing it to fit them.
+	#[inline]
+	pub fn grow_union_with(&mut self, other: Borrowed) {
+		self.grow_chunks(other.data.len());
+
+		for (offset, &value) in other.data.iter().enumerate() {
+			self.union_chunk(offset, value);
+		}
+	}
+
+	/// Inserts the values of every set in `slices` in turn, growing the set
+	/// to fit them.
+	#[inline]
+	pub fn union_extend<'data, I: IntoIterator<Item = Borrowed<'data>>>(&mut self, slices: I) {
+		for other in slices {
+			self.grow_union_with(other);
+		}
+	}
+
+	/// Grows the set to fit every value of `other`, up to its last non-empty
+	/// chunk, so that inserting them does not reallocate afterwards.
+	#[inline]
+	pub fn reserve_like(&mut self, other: Borrowed) {
+		self.reserve_exact_chunks(other.trimmed().len());
+	}
+
+	/// Inserts the values of `other` into the set, growing it at most once.
+	///
+	/// Unlike [`Owned::clone_from_slice`], the existing values are kept.
+	#[inline]
+	pub fn extend_from_slice_values(&mut self, other: Borrowed) {
+		self.grow_union_with(other);
+	}
+
+	fn union_at<I: IntoIterator<Item = Inner>>(&mut self, words: I, offset: usize) {
+		let start = crate::inner::bits_to_chunk(offset);
+		let shift = crate::inner::shift(offset);
+
+		for (position, word) in words.into_iter().enumerate() {
+			if word == 0 {
+				continue;
+			}
+
+			let position = start + position;
+			let high = word.checked_shr(Inner::BITS - shift).unwrap_or(0);
+
+			self.grow_chunks(position + 1 + usize::from(high != 0));
+			self.union_chunk(position, word << shift);
+
+			if high != 0 {
+				self.union_chunk(position + 1, high);
+			}
+		}
+	}
+
+	/// Inserts the values of `other` increased by `offset`, growing the set to fit them.
+	pub fn union_shifted(&mut self, other: Borrowed, offset: usize) {
+		let words = other.trimmed();
+
+		if let Some(&last) = words.last() {
+			let value = crate::inner::chunk_to_bits(words.len() - 1)
+				+ (Inner::BITS - 1 - last.leading_zeros()) as usize;
+
+			self.grow_chunks(crate::inner::bits_to_chunk(offset + value) + 1);
+		}
+
+		self.union_at(words.iter().copied(), offset);
+	}
+
+	/// Inserts the values of a bitmap packed least significant bit first into
+	/// `bytes`, increased by `bit_offset`, growing the set to fit them.
+	pub fn insert_from_bytes(&mut self, bytes: &[u8], bit_offset: usize) {
+		if let Some(last) = bytes.iter().rposition(|&byte| byte != 0) {
+			let value = last * 8 + bytes[last].ilog2() as usize;
+
+			self.grow_chunks(crate::inner::bits_to_chunk(bit_offset + value) + 1);
+		}
+
+		let words = bytes.chunks(core::mem::size_of::<Inner>()).map(|chunk| {
+			let mut buffer = [0; core::mem::size_of::<Inner>()];
+
+			buffer[..chunk.len()].copy_from_slice(chunk);
+
+			Inner::from_le_bytes(buffer)
+		});
+
+		self.union_at(words, bit_offset);
+	}
+
+	/// Inserts the values of `self` in the given exclusive range into `dst`,
+	/// moved to begin at `dst_offset`, growing it to fit them.
+	pub fn copy_range_into(&self, start: usize, end: usize, dst: &mut Self, dst_offset: usize) {
+		let source = self.as_slice();
+		let chunks = crate::inner::range_chunks(start, end);
+		let word = {
+			let chunks = chunks.clone();
+
+			move |offset| {
+				if chunks.contains(&offset) {
+					source.chunk(offset) & crate::inner::range_mask(start, end, offset)
+				} else {
+					0
+				}
+			}
+		};
+
+		let lead = crate::inner::shift(start) as usize;
+
+		if let Some(offset) = dst_offset.checked_sub(lead) {
+			dst.union_at(chunks.map(word), offset);
+		} else {
+			let shift = crate::inner::shift(lead - dst_offset);
+			let words = chunks.map(move |offset| {
+				(word(offset) >> shift) | (word(offset + 1) << (Inner::BITS - shift))
+			});
+
+			dst.union_at(words, 0);
+		}
+	}
+
+	/// Moves each value `v` below `modulus` to `(v + amount) % modulus`,
+	/// growing the set to fit them. Values at or above `modulus` are left
+	/// untouched.
+	pub fn rotate(&mut self, amount: usize, modulus: usize) {
+		let Some(amount) = amount.checked_rem(modulus).filter(|&amount| amount != 0) else {
+			return;
+		};
+
+		let mut rotated = Self::new();
+
+		self.copy_range_into(0, modulus - amount, &mut rotated, amount);
+		self.copy_range_into(modulus - amount, modulus, &mut rotated, 0);
+
+		// SAFETY: The range is clamped to the maximum of the set.
+		unsafe {
+			self.remove_all(0, modulus.min(self.maximum()))
+				.unwrap_unchecked();
+		}
+
+		self.grow_union_with(rotated.as_slice());
+	}
+
+	fn xor_chunk(&mut self, offset: usize, value: Inner) {
+		let inner = &mut self.data[offset];
+		let before = inner.count_ones() as usize;
+
+		*inner ^= value;
+
+		self.len = self.len - before + inner.count_ones() as usize;
+	}
+
+	/// Toggles the values of `other` in the set, growing it to fit them.
+	#[inline]
+	pub fn symmetric_difference_with(&mut self, other: Borrowed) {
+		let source = other.trimmed();
+
+		self.grow_chunks(source.len());
+
+		for (offset, &value) in source.iter().enumerate() {
+			self.xor_chunk(offset, value);
+		}
+	}
+
+	/// Toggles the values of `other` in the set, growing it to fit them.
+	///
+	/// This is the same as [`Owned::symmetric_difference_with`].
+	#[inline]
+	pub fn xor_with(&mut self, other: Borrowed) {
+		self.symmetric_difference_with(other);
+	}
+
+	/// Toggles the values of `other` within the given exclusive range in the
+	/// set, growing it to fit them.
+	pub fn xor_range_with(&mut self, other: Borrowed, start: usize, end: usize) {
+		let chunks = crate::inner::range_chunks(start, end);
+		let chunks = chunks.start..chunks.end.min(other.data.len());
+
+		self.grow_chunks(chunks.end);
+
+		for offset in chunks {
+			let value = other.data[offset] & crate::inner::range_mask(start, end, offset);
+
+			self.xor_chunk(offset, value);
+		}
+	}
+
+	/// Removes the values of `other` from the set.
+	#[inline]
+	pub fn difference_with(&mut self, other: Borrowed) {
+		let mut removed = 0;
+
+		for (inner, &value) in self.data.iter_mut().zip(other.data) {
+			removed += (*inner & value).count_ones() as usize;
+
+			*inner &= !value;
+		}
+
+		self.len -= removed;
+	}
+
+	/// Removes the values of `other` from the set.
+	///
+	/// This is the same as [`Owned::difference_with`].
+	#[inline]
+	pub fn andnot_with(&mut self, other: Borrowed) {
+		self.difference_with(other);
+	}
+
+	/// Returns a set where each value `v` below `maximum` becomes `maximum - 1 - v`.
+	#[must_use]
+	pub fn mirror(&self, maximum: usize) -> Self {
+		let source = self.as_slice();
+		let chunks = crate::inner::range_chunks(0, maximum).end;
+		let padding =
+			crate::inner::shift(Inner::BITS as usize - crate::inner::shift(maximum) as usize);
+
+		let reversed = |offset: usize| {
+			let offset = chunks - 1 - offset;
+			let inner = source.chunk(offset) & crate::inner::range_mask(0, maximum, offset);
+
+			inner.reverse_bits()
+		};
+
+		let data: Box<[Inner]> = (0..chunks)
+			.map(|offset| {
+				let low = reversed(offset) >> padding;
+				let high = if padding != 0 && offset + 1 < chunks {
+					reversed(offset + 1) << (Inner::BITS - padding)
+				} else {
+					0
+				};
+
+				low | high
+			})
+			.collect();
+
+		let len = crate::inner::count_ones(&data);
+
+		Self { data, len }
+	}
+
+	/// Encodes the set as a list of runs, each stored as the varint gap from
+	/// the end of the previous run followed by the varint run length.
+	#[must_use]
+	pub fn to_runs_bytes(&self) -> Vec<u8> {
+		let mut bytes = Vec::new();
+		let mut previous = 0;
+
+		for (start, end) in self.as_slice().runs() {
+			write_varint(&mut bytes, start - previous);
+			write_varint(&mut bytes, end - start);
+
+			previous = end;
+		}
+
+		bytes
+	}
+
+	/// Decodes a set from the list of runs produced by [`Owned::to_runs_bytes`],
+	/// or returns `None` if the bytes are malformed or the runs cannot be
+	/// allocated.
+	#[must_use]
+	pub fn from_runs_bytes(mut bytes: &[u8]) -> Option<Self> {
+		let mut set = Self::new();
+		let mut previous = 0_usize;
+
+		while !bytes.is_empty() {
+			let start = previous.checked_add(read_varint(&mut bytes)?)?;
+			let end = start.checked_add(read_varint(&mut bytes)?)?;
+
+			set.try_grow_maximum(end).ok()?;
+			set.grow_insert_all(start, end);
+
+			previous = end;
+		}
+
+		Some(set)
+	}
+
+	/// Decodes a set from bytes packed most significant bit first, as
+	/// produced by [`Borrowed::to_msb_bytes`].
+	#[must_use]
+	pub fn from_msb_bytes(bytes: &[u8]) -> Self {
+		let data: Box<[Inner]> = bytes
+			.chunks(core::mem::size_of::<Inner>())
+			.map(|chunk| {
+				let mut buffer = [0; core::mem::size_of::<Inner>()];
+
+				for (word, &byte) in buffer.iter_mut().zip(chunk) {
+					*word = byte.reverse_bits();
+				}
+
+				Inner::from_le_bytes(buffer)
+			})
+			.collect();
+
+		let len = crate::inner::count_ones(&data);
+
+		Self { data, len }
+	}
+
+	/// Returns the smallest value missing from the union of `sets`, without
+	/// building the union.
+	#[must_use]
+	pub fn mex_of_union(sets: &[Borrowed]) -> usize {
+		let chunks = sets.iter().map(|set| set.data.len()).max().unwrap_or(0);
+
+		for offset in 0..chunks {
+			let inner = sets.iter().fold(0, |inner, set| inner | set.chunk(offset));
+
+			if inner != Inner::MAX {
+				return crate::inner::chunk_to_bits(offset) + inner.trailing_ones() as usize;
+			}
+		}
+
+		crate::inner::chunk_to_bits(chunks)
+	}
+
+	/// Returns an adaptor that formats every value, unlike the [`Debug`]
+	/// implementation which stops after the first few.
+	///
+	/// [`Debug`]: core::fmt::Debug
+	#[inline]
+	pub fn debug_full(&self) -> impl core::fmt::Debug + '_ {
+		self.as_slice().debug_full()
+	}
+
+	/// Returns an adaptor that formats the length, maximum, occupied chunk
+	/// count and run count of the set instead of its values.
+	#[inline]
+	pub fn debug_stats(&self) -> impl core::fmt::Debug + '_ {
+		DebugStats(self.as_slice())
+	}
+
+	/// Clones the data from `source` without allocating if possible.
+	#[inline]
+	pub fn clone_from_slice(&mut self, source: Borrowed) {
+		if let Some((head, tail)) = self.data.split_at_mut_checked(source.data.len()) {
+			head.copy_from_slice(source.data);
+			tail.fill(0);
+		} else {
+			self.clone_from_slice_grow(source);
+		}
+
+		self.len = source.len;
+	}
+
+	#[inline(never)]
+	fn clone_from_slice_grow(&mut self, source: Borrowed) {
+		self.with_buffer(move |data| {
+			data.clear();
+			data.extend_from_slice(source.data);
+
+			data.resize(data.capacity(), 0);
+
+			debug_assert_eq!(data.len(), data.capacity(), "buffer should not reallocate");
+		});
+	}
+}
+
+/// Returns the chunks that a set of `current` chunks grows to for holding
+/// `0..maximum` within [`Owned::MAX_CHUNKS`], and whether that is too few.
+fn saturating_chunks(current: usize, maximum: usize) -> (usize, bool) {
+	let needed = crate::inner::range_chunks(0, maximum).end;
+
+	if needed <= current {
+		return (current, false);
+	}
+
+	let chunks = needed.max(current.saturating_mul(2));
+	let chunks = chunks.min(Owned::MAX_CHUNKS.max(current));
+
+	(chunks, needed > chunks)
+}
+
+fn range_bounds<R: RangeBounds<usize>>(range: &R, maximum: usize) -> (usize, usize) {
+	let start = match range.start_bound() {
+		Bound::Included(&start) => start,
+		Bound::Excluded(&start) => start.saturating_add(1),
+		Bound::Unbounded => 0,
+	};
+
+	let end = match range.end_bound() {
+		Bound::Included(&end) => end.saturating_add(1),
+		Bound::Excluded(&end) => end,
+		Bound::Unbounded => maximum,
+	};
+
+	(start, end)
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: usize) {
+	while value >= 0x80 {
+		bytes.push(u8::try_from(value & 0x7F).unwrap() | 0x80);
+
+		value >>= 7;
+	}
+
+	bytes.push(u8::try_from(value).unwrap());
+}
+
+fn read_varint(bytes: &mut &[u8]) -> Option<usize> {
+	let mut value = 0;
+	let mut shift = 0;
+
+	loop {
+		let (&byte, rest) = bytes.split_first()?;
+		let part = usize::from(byte & 0x7F);
+
+		*bytes = rest;
+
+		if shift >= usize::BITS || (part << shift) >> shift != part {
+			return None;
+		}
+
+		value |= part << shift;
+		shift += 7;
+
+		if byte & 0x80 == 0 {
+			return Some(value);
+		}
+	}
+}
+
+impl Default for Owned {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Clone for Owned {
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			data: self.data.clone(),
+			len: self.len,
+		}
+	}
+
+	#[inline]
+	fn clone_from(&mut self, source: &Self) {
+		let source = source.as_slice();
+
+		self.clone_from_slice(source);
+	}
+}
+
+impl PartialEq for Owned {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.as_slice().eq(&other.as_slice())
+	}
+}
+
+impl Eq for Owned {}
+
+impl PartialOrd for Owned {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for Owned {
+	#[inline]
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+		self.as_slice().cmp(&other.as_slice())
+	}
+}
+
+impl PartialEq<Borrowed<'_>> for Owned {
+	#[inline]
+	fn eq(&self, other: &Borrowed<'_>) -> bool {
+		self.as_slice().eq(other)
+	}
+}
+
+impl PartialEq<Owned> for Borrowed<'_> {
+	#[inline]
+	fn eq(&self, other: &Owned) -> bool {
+		self.eq(&other.as_slice())
+	}
+}
+
+impl PartialOrd<Borrowed<'_>> for Owned {
+	#[inline]
+	fn partial_cmp(&self, other: &Borrowed<'_>) -> Option<core::cmp::Ordering> {
+		Some(self.as_slice().cmp(other))
+	}
+}
+
+impl PartialOrd<Owned> for Borrowed<'_> {
+	#[inline]
+	fn partial_cmp(&self, other: &Owned) -> Option<core::cmp::Ordering> {
+		Some(self.cmp(&other.as_slice()))
+	}
+}
+
+impl core::ops::Sub<Borrowed<'_>> for &Owned {
+	type Output = Owned;
+
+	#[inline]
+	fn sub(self, rhs: Borrowed<'_>) -> Self::Output {
+		let mut result = Owned::new();
+
+		self.as_slice().difference_into(rhs, &mut result);
+
+		result
+	}
+}
+
+impl core::ops::BitOr for Owned {
+	type Output = Self;
+
+	#[inline]
+	fn bitor(self, rhs: Self) -> Self::Output {
+		let (mut larger, smaller) = if self.data.len() >= rhs.data.len() {
+			(self, rhs)
+		} else {
+			(rhs, self)
+		};
+
+		for (offset, &value) in smaller.data.iter().enumerate() {
+			larger.union_chunk(offset, value);
+		}
+
+		larger
+	}
+}
+
+impl Extend<usize> for Owned {
+	#[inline]
+	fn extend<T: IntoIterator<Item = usize>>(&mut self, iter: T) {
+		iter.into_iter().for_each(|index| {
+			self.grow_insert(index);
+		});
+	}
+}
+
+impl FromIterator<usize> for Owned {
+	#[inline]
+	fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+		let mut set = Self::new();
+
+		iter.into_iter().for_each(|index| {
+			set.grow_insert(index);
+		});
+
+		set
+	}
+}
+
+/// Fills the range chunk by chunk, which is faster than collecting it as
+/// [`FromIterator`] inserts values one at a time.
+impl From<core::ops::Range<usize>> for Owned {
+	#[inline]
+	fn from(range: core::ops::Range<usize>) -> Self {
+		let mut set = Self::new();
+
+		set.reset_to_range(range.start, range.end);
+		set
+	}
+}
+
+impl From<&BTreeSet<usize>> for Owned {
+	#[inline]
+	fn from(set: &BTreeSet<usize>) -> Self {
+		let mut result = Self::new();
+
+		if let Some(&last) = set.last() {
+			result.grow_chunks(crate::inner::bits_to_chunk(last) + 1);
+		}
+
+		for &index in set {
+			result.data[crate::inner::bits_to_chunk(index)] |= crate::inner::mask(index);
+		}
+
+		result.len = set.len();
+		result
+	}
+}
+
+impl From<BTreeSet<usize>> for Owned {
+	#[inline]
+	fn from(set: BTreeSet<usize>) -> Self {
+		Self::from(&set)
+	}
+}
+
+#[cfg(feature = "fixedbitset")]
+const BLOCKS_PER_CHUNK: usize = (Inner::BITS / usize::BITS) as usize;
+
+#[cfg(feature = "fixedbitset")]
+impl From<&fixedbitset::FixedBitSet> for Owned {
+	#[inline]
+	fn from(set: &fixedbitset::FixedBitSet) -> Self {
+		let data: Box<[Inner]> = set
+			.as_slice()
+			.chunks(BLOCKS_PER_CHUNK)
+			.map(|blocks| {
+				blocks.iter().enumerate().fold(0, |inner, (index, &block)| {
+					inner | (block as Inner) << (index * usize::BITS as usize)
+				})
+			})
+			.collect();
+
+		let len = crate::inner::count_ones(&data);
+
+		Self { data, len }
+	}
+}
+
+#[cfg(feature = "fixedbitset")]
+impl From<&Owned> for fixedbitset::FixedBitSet {
+	#[allow(clippy::cast_possible_truncation)]
+	#[inline]
+	fn from(set: &Owned) -> Self {
+		let blocks = set.data.iter().flat_map(|&inner| {
+			(0..BLOCKS_PER_CHUNK)
+				.map(move |index| (inner >> (index * usize::BITS as usize)) as usize)
+		});
+
+		Self::with_capacity_and_blocks(set.maximum(), blocks)
+	}
+}
+
+impl core::fmt::Debug for Owned {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.as_slice().fmt(f)
+	}
+}
+
+struct Drain<'data> {
+	set: &'data mut Owned,
+	offset: usize,
+	cached: Iter,
+}
+
+impl Iterator for Drain<'_> {
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(index) = self.cached.next() {
+				return Some(crate::inner::chunk_to_bits(self.offset - 1) + usize::from(index));
+			}
+
+			if self.set.len == 0 {
+				return None;
+			}
+
+			let inner = core::mem::take(&mut self.set.data[self.offset]);
+
+			self.set.len -= inner.count_ones() as usize;
+			self.cached = Iter::new(inner);
+			self.offset += 1;
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.set.len + self.cached.len();
+
+		(len, Some(len))
+	}
+}
+
+impl ExactSizeIterator for Drain<'_> {}
+
+impl FusedIterator for Drain<'_> {}
+
+impl Drop for Drain<'_> {
+	fn drop(&mut self) {
+		self.set.clear();
+	}
+}
+
+struct DebugStats<'data>(Borrowed<'data>);
+
+impl core::fmt::Debug for DebugStats<'_> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("Stats")
+			.field("len", &self.0.len())
+			.field("maximum", &self.0.maximum())
+			.field("occupied_chunks", &self.0.occupied_chunks())
+			.field("run_count", &self.0.runs().count())
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use alloc::{collections::BTreeSet, vec::Vec};
+
+	use super::{Borrowed, Inner, Owned};
+
+	#[test]
+	fn values_iteration() {
+		let mut set = Owned::new();
+		let mut list = Vec::new();
+
+		for index in 0..10 {
+			let value = 2_usize.pow(index);
+
+			set.grow_insert(value);
+			list.push(value);
+		}
+
+		assert!(
+			set.ascending().eq(list.iter().copied()),
+			"ascending list should be ordered"
+		);
+
+		assert!(
+			set.descending().eq(list.iter().copied().rev()),
+			"descending list should be ordered"
+		);
+	}
+
+	#[test]
+	fn bulk_operation() {
+		let mut set = Owned::new();
+
+		set.grow_insert_all(30, 300);
+
+		assert!(
+			(30..300).eq(set.ascending()),
+			"insertion should have succeeded"
+		);
+
+		assert!(set.validate(), "length should match the set bits");
+
+		set.remove_all(60, 240).unwrap();
+
+		assert!(set.validate(), "length should match the set bits");
+
+		assert!(
+			(30..60).chain(240..300).eq(set.ascending()),
+			"insertion should have succeeded"
+		);
+	}
+
+	#[test]
+	fn ascending_from_start() {
+		let set: Owned = (0..300).step_by(7).collect();
+
+		for start in [0, 1, 63, 64, 65, 140, 299, 300, 1000] {
+			let iter = set.as_slice().ascending_from(start);
+			let list: Vec<_> = (0..300)
+				.step_by(7)
+				.filter(|&value| value >= start)
+				.collect();
+
+			assert_eq!(
+				iter.len(),
+				list.len(),
+				"length should match remaining values"
+			);
+
+			assert!(iter.eq(list), "iteration should start at `start`");
+		}
+	}
+
+	#[test]
+	fn descending_from_start() {
+		let set: Owned = (0..300).step_by(7).collect();
+
+		for start in [0, 1, 63, 64, 65, 140, 299, 300, 1000] {
+			let iter = set.as_slice().descending_from(start);
+			let list: Vec<_> = (0..300)
+				.step_by(7)
+				.rev()
+				.filter(|&value| value <= start)
+				.collect();
+
+			assert_eq!(
+				iter.len(),
+				list.len(),
+				"length should match remaining values"
+			);
+
+			assert!(iter.eq(list), "iteration should start at `start`");
+		}
+	}
+
+	#[test]
+	fn union_shifted() {
+		let other: Owned = [0, 1, 63, 64, 100, 127].into_iter().collect();
+
+		for offset in [0, 1, 63, 64, 65, 200] {
+			let mut set: Owned = [2, 70].into_iter().collect();
+
+			set.union_shifted(other.as_slice(), offset);
+
+			let mut list: Vec<_> = other.ascending().map(|value| value + offset).collect();
+
+			list.extend([2, 70]);
+			list.sort_unstable();
+			list.dedup();
+
+			assert_eq!(set.len(), list.len(), "length should match merged values");
+			assert!(
+				set.ascending().eq(list),
+				"values should be shifted by `offset`"
+			);
+		}
+	}
+
+	#[test]
+	fn mirror_values() {
+		let set: Owned = [0, 5, 63, 64, 99, 150].into_iter().collect();
+
+		for maximum in [0, 1, 64, 100, 128, 151] {
+			let mirrored = set.mirror(maximum);
+			let mut list: Vec<_> = set
+				.ascending()
+				.filter(|&value| value < maximum)
+				.map(|value| maximum - 1 - value)
+				.collect();
+
+			list.sort_unstable();
+
+			assert_eq!(
+				mirrored.len(),
+				list.len(),
+				"length should match values in range"
+			);
+
+			assert!(mirrored.ascending().eq(list), "values should be reflected");
+		}
+	}
+
+	#[test]
+	fn clone_from_empty() {
+		let mut set = Owned::new();
+
+		set.grow_insert_all(0, 500);
+
+		let maximum = set.maximum();
+
+		set.clone_from(&Owned::new());
+
+		assert_eq!(set.len(), 0, "length should be reset");
+		assert_eq!(set.ascending().next(), None, "set should be empty");
+		assert_eq!(set.maximum(), maximum, "capacity should be preserved");
+	}
+
+	#[test]
+	fn high_chunk_only() {
+		let mut set = Owned::with_maximum(1000);
+
+		set.insert(200).unwrap();
+
+		assert!(set.ascending().eq([200]), "ascending should only yield 200");
+		assert!(
+			set.descending().eq([200]),
+			"descending should only yield 200"
+		);
+
+		let mut set = Owned::new();
+
+		set.grow_insert(64);
+
+		assert!(set.ascending().eq([64]), "ascending should only yield 64");
+		assert!(set.descending().eq([64]), "descending should only yield 64");
+	}
+
+	#[test]
+	fn runs_bytes_round_trip() {
+		let set: Owned = [0, 1, 2, 63, 64, 65, 200, 1000, 1001].into_iter().collect();
+		let bytes = set.to_runs_bytes();
+
+		assert_eq!(
+			Owned::from_runs_bytes(&bytes),
+			Some(set),
+			"runs should decode to the original set"
+		);
+
+		assert_eq!(
+			Owned::from_runs_bytes(&[]),
+			Some(Owned::new()),
+			"no bytes should decode to the empty set"
+		);
+
+		assert_eq!(
+			Owned::from_runs_bytes(&[0x80]),
+			None,
+			"truncated varint should be rejected"
+		);
+	}
+
+	#[test]
+	fn bitor_by_value() {
+		let small: Owned = [1, 5].into_iter().collect();
+		let large: Owned = [5, 300].into_iter().collect();
+		let maximum = large.maximum();
+		let result = small | large;
+
+		assert!(
+			result.ascending().eq([1, 5, 300]),
+			"union should hold the values of both sets"
+		);
+
+		assert_eq!(
+			result.len(),
+			3,
+			"union length should count shared values once"
+		);
+
+		assert_eq!(
+			result.maximum(),
+			maximum,
+			"union should keep the larger buffer"
+		);
+	}
+
+	#[test]
+	fn slice_bounds() {
+		let set: Owned = [7, 64, 130].into_iter().collect();
+
+		assert_eq!(
+			set.as_slice().bounds(),
+			Some((7, 130)),
+			"bounds should be the first and last values"
+		);
+
+		assert_eq!(
+			Owned::with_maximum(256).as_slice().bounds(),
+			None,
+			"empty set should have no bounds"
+		);
+	}
+
+	#[test]
+	fn proper_subset() {
+		let small: Owned = [1, 70].into_iter().collect();
+		let large: Owned = [1, 70, 200].into_iter().collect();
+
+		assert!(
+			small.as_slice().is_proper_subset(large.as_slice()),
+			"smaller set should be a proper subset"
+		);
+
+		assert!(
+			large.as_slice().is_proper_superset(small.as_slice()),
+			"larger set should be a proper superset"
+		);
+
+		assert!(
+			small.as_slice().is_subset(small.as_slice()),
+			"set should be a subset of itself"
+		);
+
+		assert!(
+			!small.as_slice().is_proper_subset(small.as_slice()),
+			"set should not be a proper subset of itself"
+		);
+
+		assert!(
+			!large.as_slice().is_subset(small.as_slice()),
+			"larger set should not be a subset"
+		);
+	}
+
+	#[test]
+	fn reserve_exact_chunks() {
+		let mut set = Owned::new();
+
+		set.reserve_exact_chunks(3);
+		assert_eq!(
+			set.as_words().len(),
+			3,
+			"chunks should grow to the requested count"
+		);
+
+		set.reserve_exact_chunks(2);
+		assert_eq!(
+			set.as_words().len(),
+			3,
+			"chunks should not shrink to a smaller count"
+		);
+	}
+
+	#[test]
+	fn fold_matches_next() {
+		let values = [0, 3, 63, 64, 190, 191, 500];
+		let set: Owned = values.into_iter().collect();
+		let push = |mut values: Vec<usize>, value| {
+			values.push(value);
+			values
+		};
+
+		for skip in 0..=values.len() {
+			let mut ascending = set.ascending();
+			let mut descending = set.descending();
+
+			ascending.by_ref().take(skip).for_each(drop);
+			descending.by_ref().take(skip).for_each(drop);
+
+			assert_eq!(
+				ascending.fold(Vec::new(), push),
+				values[skip..],
+				"fold should yield the remaining ascending values"
+			);
+
+			assert!(
+				descending
+					.fold(Vec::new(), push)
+					.into_iter()
+					.eq(values[..values.len() - skip].iter().copied().rev()),
+				"fold should yield the remaining descending values"
+			);
+		}
+	}
+
+	#[test]
+	fn from_fn_predicate() {
+		let mut calls = Vec::new();
+		let set = Owned::from_fn(10..140, |value| {
+			calls.push(value);
+
+			value % 7 == 0
+		});
+
+		assert!(
+			calls.into_iter().eq(10..140),
+			"predicate should be called for every value in the range"
+		);
+
+		assert!(
+			set.ascending().eq((14..140).step_by(7)),
+			"set should hold the values accepted by the predicate"
+		);
+
+		assert_eq!(set.len(), 18, "length should count the accepted values");
+		assert!(set.validate(), "length should match the set bits");
+	}
+
+	#[cfg(feature = "fixedbitset")]
+	#[test]
+	fn fixedbitset_round_trip() {
+		let set: Owned = [0, 9, 64, 127, 300].into_iter().collect();
+		let other = fixedbitset::FixedBitSet::from(&set);
+
+		assert!(
+			other.ones().eq(set.ascending()),
+			"conversion should keep the values"
+		);
+
+		assert_eq!(
+			Owned::from(&other),
+			set,
+			"round trip should preserve the set"
+		);
+	}
+
+	#[test]
+	fn capacity_accessors() {
+		let mut set = Owned::with_maximum(65);
+
+		assert_eq!(
+			set.chunk_capacity(),
+			2,
+			"capacity should count the allocated chunks"
+		);
+
+		assert_eq!(
+			set.bit_capacity(),
+			128,
+			"bit capacity should count the allocated bits"
+		);
+
+		assert_eq!(
+			set.maximum(),
+			set.bit_capacity(),
+			"maximum should equal the bit capacity"
+		);
+
+		set.grow_maximum(set.maximum());
+		assert_eq!(
+			set.chunk_capacity(),
+			2,
+			"capacity should be kept after removal"
+		);
+
+		assert_eq!(
+			Owned::with_maximum(64).chunk_capacity(),
+			1,
+			"one chunk should fit the values below 64"
+		);
+
+		assert_eq!(
+			Owned::with_maximum(0).chunk_capacity(),
+			0,
+			"no chunks should be needed for no values"
+		);
+	}
+
+	#[test]
+	fn enumerate_ranks() {
+		let set: Owned = [4, 70, 90].into_iter().collect();
+		let ranks = set.as_slice().enumerate_ranks();
+
+		assert_eq!(ranks.len(), 3, "ranks should be exact sized");
+		assert!(
+			ranks.eq([(0, 4), (1, 70), (2, 90)]),
+			"ranks should count the smaller values"
+		);
+	}
+
+	#[test]
+	fn clear_and_shrink() {
+		let mut set: Owned = [3, 500].into_iter().collect();
+
+		set.clear_and_shrink();
+
+		assert!(set.is_empty(), "set should be empty after clearing");
+		assert_eq!(
+			set.chunk_capacity(),
+			0,
+			"buffer should be released after clearing"
+		);
+	}
+
+	#[test]
+	fn count_where_chunk() {
+		let set: Owned = [0, 1, 2, 3, 64, 65, 129].into_iter().collect();
+		let even = set
+			.as_slice()
+			.count_where_chunk(|inner| inner & 0x5555_5555_5555_5555);
+
+		assert_eq!(even, 3, "predicate should count the even chunks");
+	}
+
+	#[test]
+	fn from_pairs() {
+		let set = Owned::from_pairs([(3, true), (80, false), (200, true), (3, true)]);
+
+		assert!(set.ascending().eq([3, 200]), "pairs should be inserted");
+		assert_eq!(set.len(), 2, "length should count the inserted pairs");
+	}
+
+	#[test]
+	fn andnot() {
+		let mut set: Owned = [1, 2, 70, 300].into_iter().collect();
+		let other: Owned = [2, 300, 900].into_iter().collect();
+
+		assert_eq!(
+			set.as_slice().andnot_len(other.as_slice()),
+			2,
+			"difference length should count the values only in the set"
+		);
+
+		set.andnot_with(other.as_slice());
+
+		assert!(
+			set.ascending().eq([1, 70]),
+			"difference should keep the values only in the set"
+		);
+
+		assert!(set.validate(), "length should match the set bits");
+	}
+
+	#[test]
+	fn len_after_partial_consumption() {
+		let set: Owned = [0, 5, 63, 64, 128, 129, 700].into_iter().collect();
+
+		for taken in 0..=set.len() {
+			let mut ascending = set.ascending();
+			let mut descending = set.descending();
+
+			ascending.by_ref().take(taken).for_each(drop);
+			descending.by_ref().take(taken).for_each(drop);
+
+			assert_eq!(
+				ascending.len(),
+				set.len() - taken,
+				"ascending length should shrink as values are taken"
+			);
+
+			assert_eq!(
+				ascending.count(),
+				set.len() - taken,
+				"ascending count should match its length"
+			);
+
+			assert_eq!(
+				descending.len(),
+				set.len() - taken,
+				"descending length should shrink as values are taken"
+			);
+
+			assert_eq!(
+				descending.count(),
+				set.len() - taken,
+				"descending count should match its length"
+			);
+		}
+
+		let mut ascending = set.as_slice().ascending_from(64);
+
+		assert_eq!(
+			ascending.len(),
+			4,
+			"length should not count runs not yet read"
+		);
+		ascending.next();
+		assert_eq!(
+			ascending.len(),
+			3,
+			"length should shrink by the value taken"
+		);
+	}
+
+	#[test]
+	fn ascending_next_run() {
+		let mut set: Owned = [1, 2, 3, 10].into_iter().collect();
+
+		set.grow_insert_all(60, 200);
+
+		let mut ascending = set.ascending();
+
+		assert_eq!(
+			ascending.next(),
+			Some(1),
+			"first value should be yielded alone"
+		);
+
+		assert_eq!(
+			ascending.next_run(),
+			Some((2, 4)),
+			"run should end at the first gap"
+		);
+
+		assert_eq!(
+			ascending.next_run(),
+			Some((10, 11)),
+			"single value should form a run"
+		);
+
+		assert_eq!(
+			ascending.next(),
+			Some(60),
+			"value should be yielded after a run"
+		);
+
+		assert_eq!(
+			ascending.len(),
+			139,
+			"length should count the rest of the run"
+		);
+
+		assert_eq!(
+			ascending.next_run(),
+			Some((61, 200)),
+			"run should continue across chunks"
+		);
+
+		assert_eq!(ascending.next_run(), None, "runs should be exhausted");
+		assert_eq!(ascending.next(), None, "values should be exhausted");
+	}
+
+	#[test]
+	fn slice_range() {
+		let set: Owned = [1, 64, 65, 127, 128, 400].into_iter().collect();
+		let range = set.as_slice().range(2, 128);
+
+		assert_eq!(range.len(), 3, "range length should count its values");
+		assert!(
+			range.eq([64, 65, 127]),
+			"range should yield its values only"
+		);
+
+		assert_eq!(
+			set.as_slice().count_in_range(0, 1000),
+			6,
+			"count should stop at the last value"
+		);
+
+		assert_eq!(
+			set.as_slice().range(500, 900).len(),
+			0,
+			"range past the values should be empty"
+		);
+
+		assert_eq!(
+			set.as_slice().range(9, 3).next(),
+			None,
+			"reversed range should be empty"
+		);
+	}
+
+	#[test]
+	fn owned_inclusion() {
+		let small: Owned = [2, 90].into_iter().collect();
+		let large: Owned = [2, 3, 90].into_iter().collect();
+		let other: Owned = [4, 300].into_iter().collect();
+
+		assert!(small.is_subset(&large), "smaller set should be a subset");
+		assert!(large.is_superset(&small), "larger set should be a superset");
+		assert!(
+			!large.is_subset(&small),
+			"larger set should not be a subset"
+		);
+
+		assert!(
+			small.is_disjoint(&other),
+			"sets without common values should be disjoint"
+		);
+
+		assert!(
+			!small.is_disjoint(&large),
+			"sets with common values should not be disjoint"
+		);
+	}
+
+	#[test]
+	fn gap_runs() {
+		let mut set = Owned::with_maximum(128);
+
+		set.insert_all(0, 10).unwrap();
+		set.insert_all(60, 70).unwrap();
+		set.insert(127).unwrap();
+
+		assert_eq!(
+			set.as_slice().gap_count(),
+			128 - 21,
+			"gaps should count the missing values"
+		);
+
+		assert!(
+			set.as_slice().gap_runs().eq([(10, 60), (70, 127)]),
+			"gap runs should be the missing ranges"
+		);
+
+		assert!(
+			Owned::new().as_slice().gap_runs().eq([]),
+			"empty set should have no gap runs"
+		);
+	}
+
+	#[test]
+	fn lexicographic_order() {
+		let set = |values: &[usize]| values.iter().copied().collect::<Owned>();
+
+		assert!(
+			set(&[1]) > set(&[0, 2]),
+			"order should compare the first values"
+		);
+
+		assert!(
+			set(&[0]) < set(&[0, 1]),
+			"prefix should order before longer sets"
+		);
+
+		assert!(set(&[]) < set(&[0]), "empty set should order first");
+		assert!(
+			set(&[5, 6]) < set(&[5, 70]),
+			"order should compare the first differing values"
+		);
+
+		assert_eq!(
+			set(&[3]).cmp(&set(&[3])),
+			core::cmp::Ordering::Equal,
+			"equal sets should compare equal"
+		);
+	}
+
+	#[test]
+	fn subset_order() {
+		use core::cmp::Ordering;
+
+		let set = |values: &[usize]| values.iter().copied().collect::<Owned>();
+		let order = |left: &[usize], right: &[usize]| {
+			set(left).as_slice().subset_order(set(right).as_slice())
+		};
+
+		assert_eq!(
+			order(&[1], &[0, 1]),
+			Some(Ordering::Less),
+			"smaller set should order as less"
+		);
+
+		assert_eq!(
+			order(&[0, 1], &[1]),
+			Some(Ordering::Greater),
+			"larger set should order as greater"
+		);
+
+		assert_eq!(
+			order(&[1, 100], &[1, 100]),
+			Some(Ordering::Equal),
+			"equal sets should order as equal"
+		);
+
+		assert_eq!(
+			order(&[1], &[0, 2]),
+			None,
+			"overlapping sets should be unordered"
+		);
+
+		assert_eq!(order(&[1], &[2]), None, "disjoint sets should be unordered");
+	}
+
+	#[test]
+	fn eq_across_capacities() {
+		let left: Owned = [1, 64].into_iter().collect();
+		let mut right = Owned::with_maximum(1000);
+
+		right.extend([1, 64]);
+
+		assert_eq!(
+			left, right,
+			"trailing empty chunks should not affect equality"
+		);
+
+		assert_eq!(right, left, "equality should be symmetric");
+
+		right.insert(900).unwrap();
+
+		assert_ne!(left, right, "differing values should not be equal");
+		assert_ne!(
+			Owned::new(),
+			left,
+			"empty set should not equal a set with values"
+		);
+	}
+
+	#[test]
+	fn boxed_words_round_trip() {
+		let set: Owned = [0, 65, 130].into_iter().collect();
+		let chunks = set.chunk_capacity();
+		let words = set.into_words();
+
+		assert_eq!(words.len(), chunks, "words should keep every chunk");
+
+		let set = Owned::from_boxed_words(words);
+
+		assert_eq!(
+			set.len(),
+			3,
+			"length should count the values of every chunk"
+		);
+
+		assert!(
+			set.ascending().eq([0, 65, 130]),
+			"values should be read from the words"
+		);
+	}
+
+	#[test]
+	fn lazy_combinations() {
+		let left: Owned = [1, 2, 70, 300].into_iter().collect();
+		let right: Owned = [2, 300, 900].into_iter().collect();
+
+		let intersection = left.as_slice().intersection(right.as_slice());
+
+		assert_eq!(
+			intersection.size_hint(),
+			(0, Some(3)),
+			"intersection should be bounded by the smaller length"
+		);
+
+		assert!(
+			intersection.eq([2, 300]),
+			"intersection should yield the common values"
+		);
+
+		let mut difference = left.as_slice().difference(right.as_slice());
+
+		assert_eq!(
+			difference.size_hint(),
+			(0, Some(4)),
+			"difference should be bounded by the set length"
+		);
+
+		assert_eq!(
+			difference.next(),
+			Some(1),
+			"difference should yield the first value only in the set"
+		);
+
+		assert_eq!(
+			difference.size_hint(),
+			(0, Some(3)),
+			"bound should shrink after a value is yielded"
+		);
+
+		assert!(
+			difference.eq([70]),
+			"difference should yield the rest of the values only in the set"
+		);
+	}
+
+	#[test]
+	fn take_value() {
+		let mut set: Owned = [4, 90].into_iter().collect();
+
+		assert_eq!(
+			set.take(4),
+			Some(4),
+			"taking a present value should return it"
+		);
+
+		assert_eq!(
+			set.take(4),
+			None,
+			"taking an absent value should return nothing"
+		);
+
+		assert_eq!(
+			set.take(10_000),
+			None,
+			"taking a value beyond the maximum should return nothing"
+		);
+
+		assert_eq!(set.len(), 1, "length should count the values left");
+	}
+
+	#[test]
+	fn ensure_value() {
+		let mut set = Owned::new();
+
+		assert!(set.ensure(300), "missing value should be inserted");
+		assert!(
+			!set.ensure(300),
+			"present value should not be inserted again"
+		);
+
+		assert!(
+			set.ascending().eq([300]),
+			"set should hold the ensured value"
+		);
+	}
+
+	#[test]
+	fn partition_iter() {
+		let set: Owned = [1, 64].into_iter().collect();
+		let partition = set.as_slice().partition_iter();
+
+		assert_eq!(
+			partition.len(),
+			set.maximum(),
+			"partition should cover every value below the maximum"
+		);
+
+		assert!(
+			partition.eq((0..set.maximum()).map(|value| (value, value == 1 || value == 64))),
+			"partition should pair each value with its presence"
+		);
+	}
+
+	#[test]
+	fn xor_range() {
+		let mut set: Owned = [1, 2, 70].into_iter().collect();
+		let other: Owned = [2, 3, 70, 71, 500].into_iter().collect();
+
+		set.xor_range_with(other.as_slice(), 3, 71);
+
+		assert!(
+			set.ascending().eq([1, 2, 3]),
+			"toggle should only affect the range"
+		);
+
+		set.xor_with(other.as_slice());
+
+		assert!(
+			set.ascending().eq([1, 70, 71, 500]),
+			"toggle should flip values across chunks"
+		);
+
+		assert!(set.validate(), "length should match the set bits");
+	}
+
+	#[test]
+	fn full_set() {
+		for maximum in [0, 1, 63, 64, 65, 200] {
+			let set = Owned::full(maximum);
+
+			assert!(
+				set.ascending().eq(0..maximum),
+				"full set should hold every value below the maximum"
+			);
+
+			assert_eq!(set.len(), maximum, "length should count every value");
+			assert!(set.validate(), "length should match the set bits");
+		}
+	}
+
+	#[test]
+	fn range_bounds() {
+		let mut set = Owned::with_maximum(128);
+
+		set.insert_range(10..=20).unwrap();
+		set.insert_range(..3).unwrap();
+		set.insert_range(120..).unwrap();
+
+		assert_eq!(
+			set.len(),
+			11 + 3 + 8,
+			"length should count the values of every range"
+		);
+
+		assert!(
+			set.insert_range(100..=200).is_none(),
+			"range beyond the maximum should be rejected"
+		);
+
+		set.remove_range(..=10).unwrap();
+		set.grow_insert_range(300..302);
+
+		assert!(
+			set.ascending().take(10).eq(11..=20),
+			"excluded start should not be inserted"
+		);
+
+		assert!(set.contains(301), "unbounded end should reach the maximum");
+	}
+
+	#[test]
+	fn sorted_merge() {
+		let mut iters = [
+			Vec::from([1, 5, 64, 400]).into_iter(),
+			Vec::from([5, 6, 300]).into_iter(),
+			Vec::new().into_iter(),
+		];
+		let set = Owned::from_sorted_merge(&mut iters);
+
+		assert!(
+			set.ascending().eq([1, 5, 6, 64, 300, 400]),
+			"merge should yield each value once in order"
+		);
+
+		assert_eq!(set.len(), 6, "length should count the merged values");
+	}
+
+	#[test]
+	fn try_grow_maximum() {
+		let mut set = Owned::new();
+
+		assert!(
+			set.try_grow_maximum(100).is_ok(),
+			"growth within memory should succeed"
+		);
+
+		assert_eq!(
+			set.chunk_capacity(),
+			2,
+			"growth should allocate the needed chunks"
+		);
+
+		assert!(
+			set.try_grow_maximum(usize::MAX).is_err(),
+			"impossible growth should fail"
+		);
+
+		assert_eq!(
+			set.chunk_capacity(),
+			2,
+			"failed growth should leave the set unchanged"
+		);
+	}
+
+	#[test]
+	fn slice_descending_range() {
+		let set: Owned = [1, 64, 65, 127, 128, 400].into_iter().collect();
+		let range = set.as_slice().descending_range(2, 128);
+
+		assert_eq!(range.len(), 3, "range length should count its values");
+		assert!(
+			range.eq([127, 65, 64]),
+			"range should yield its values in descending order"
+		);
+
+		assert!(
+			set.as_slice()
+				.descending_range(0, 10_000)
+				.eq(set.descending()),
+			"range covering the set should yield every value"
+		);
+
+		assert_eq!(
+			set.as_slice().descending_range(0, 0).next(),
+			None,
+			"empty range should yield nothing"
+		);
+	}
+
+	#[test]
+	fn cross_type_comparison() {
+		let left: Owned = [1, 80].into_iter().collect();
+		let right: Owned = [2].into_iter().collect();
+
+		assert!(left.as_slice() == left, "slice should equal its set");
+		assert!(
+			left != right.as_slice(),
+			"differing sets should not be equal"
+		);
+
+		assert!(
+			left.as_slice() < right,
+			"slice should order before a larger set"
+		);
+
+		assert!(
+			right > left.as_slice(),
+			"set should order after a smaller slice"
+		);
+	}
+
+	#[test]
+	fn bulk_all_or_nothing() {
+		let mut set = Owned::with_maximum(128);
+
+		assert!(
+			set.insert_all(100, 200).is_none(),
+			"range beyond the maximum should be rejected"
+		);
+
+		assert!(set.is_empty(), "rejected insert should not change the set");
+
+		set.insert_all(100, 128).unwrap();
+
+		assert!(
+			set.remove_all(120, 300).is_none(),
+			"range beyond the maximum should be rejected"
+		);
+
+		assert_eq!(set.len(), 28, "rejected removal should not change the set");
+	}
+
+	#[test]
+	fn pop_min_batches() {
+		let mut set: Owned = [3, 9, 64, 200, 201].into_iter().collect();
+
+		assert!(
+			set.pop_min_n(2).eq([3, 9]),
+			"smallest values should be popped first"
+		);
+
+		assert_eq!(set.len(), 3, "length should count the values left");
+		assert_eq!(
+			set.pop_min_n(10).collect::<Vec<_>>(),
+			[64, 200, 201],
+			"remaining values should be popped in order"
+		);
+
+		assert!(
+			set.is_empty(),
+			"set should be empty after popping every value"
+		);
+
+		assert_eq!(
+			set.pop_min_n(1).next(),
+			None,
+			"empty set should have nothing to pop"
+		);
+	}
+
+	#[test]
+	fn shared_snapshot() {
+		let set: Owned = [2, 100].into_iter().collect();
+		let shared = crate::SharedSet::from(set.clone());
+		let copy = shared.clone();
+
+		assert_eq!(copy, shared, "clone should share the snapshot");
+		assert!(
+			copy.ascending().eq([2, 100]),
+			"snapshot should keep its values"
+		);
+
+		assert!(copy.contains(100), "snapshot should contain its values");
+
+		let mut changed = copy.to_set();
+
+		changed.insert(3).unwrap();
+
+		assert_eq!(shared.to_set(), set, "copy should equal the original set");
+		assert_eq!(
+			changed.len(),
+			3,
+			"changes to the copy should not affect the snapshot"
+		);
+	}
+
+	#[test]
+	fn count_below_above() {
+		let set: Owned = [0, 10, 64, 127].into_iter().collect();
+
+		assert_eq!(
+			set.count_below(10),
+			1,
+			"count should include the values below"
+		);
+
+		assert_eq!(
+			set.count_above(10),
+			2,
+			"count should include the values above"
+		);
+
+		assert_eq!(
+			set.count_below(10_000),
+			4,
+			"count past the end should include every value"
+		);
+
+		assert_eq!(
+			set.count_above(127),
+			0,
+			"count above the last value should be zero"
+		);
+
+		assert_eq!(
+			set.count_above(10_000),
+			0,
+			"count past the maximum should be zero"
+		);
+
+		assert_eq!(
+			set.count_above(usize::MAX),
+			0,
+			"count above the largest value should be zero"
+		);
+	}
+
+	#[test]
+	fn insert_from_bytes() {
+		let bytes = [0b0000_0101, 0, 0, 0, 0, 0, 0, 0, 0b1000_0000, 0x01];
+
+		for bit_offset in [0, 3, 64, 70] {
+			let mut set: Owned = [1].into_iter().collect();
+
+			set.insert_from_bytes(&bytes, bit_offset);
+
+			let mut expected = alloc::vec![1];
+
+			expected.extend([0, 2, 71, 72].map(|value| value + bit_offset));
+			expected.sort_unstable();
+			expected.dedup();
+
+			assert!(
+				set.ascending().eq(expected),
+				"bytes should be inserted at the offset"
+			);
+
+			assert!(set.validate(), "length should match the set bits");
+		}
+	}
+
+	#[test]
+	fn const_construction() {
+		const WORDS: &[Inner] = &[0b1010, 0, 1];
+		const SLICE: Borrowed<'static> = Borrowed::from_words(WORDS);
+
+		assert_eq!(SLICE.len(), 3, "const slice should count its values");
+		assert!(
+			SLICE.ascending().eq([1, 3, 128]),
+			"const slice should yield its values"
+		);
+
+		assert_eq!(
+			Owned::EMPTY,
+			Owned::new(),
+			"empty constant should equal a new set"
+		);
+	}
+
+	#[test]
+	fn ascending_array_chunks() {
+		let set: Owned = (0..200).step_by(9).collect();
+		let mut chunks = set.ascending().array_chunks::<8>();
+
+		assert_eq!(chunks.len(), 2, "arrays should be counted up front");
+		assert_eq!(
+			chunks.next(),
+			Some([0, 9, 18, 27, 36, 45, 54, 63]),
+			"first array should hold the first values"
+		);
+
+		assert!(chunks.next().is_some(), "second array should be full");
+		assert_eq!(chunks.next(), None, "arrays should be exhausted");
+		assert!(
+			chunks.into_remainder().eq((144..200).step_by(9)),
+			"remainder should hold the values that do not fill an array"
+		);
+	}
+
+	#[test]
+	fn singleton() {
+		let mut set = Owned::with_maximum(256);
+
+		assert_eq!(
+			set.as_singleton(),
+			None,
+			"empty set should not be a singleton"
+		);
+
+		set.insert(190).unwrap();
+		assert_eq!(
+			set.as_singleton(),
+			Some(190),
+			"single value should be a singleton"
+		);
+
+		set.insert(3).unwrap();
+		assert_eq!(
+			set.as_singleton(),
+			None,
+			"two values should not be a singleton"
+		);
+	}
+
+	#[test]
+	fn copy_range_into() {
+		let source: Owned = [1, 5, 63, 64, 100, 130, 200].into_iter().collect();
+
+		for (start, end, dst_offset) in [(0, 300, 0), (5, 131, 2), (64, 128, 0), (63, 201, 1000)] {
+			let mut dst: Owned = [7].into_iter().collect();
+
+			source.copy_range_into(start, end, &mut dst, dst_offset);
+
+			let mut expected: Vec<_> = source
+				.ascending()
+				.filter(|value| (start..end).contains(value))
+				.map(|value| value - start + dst_offset)
+				.chain([7])
+				.collect();
+
+			expected.sort_unstable();
+			expected.dedup();
+
+			assert!(
+				dst.ascending().eq(expected),
+				"range should be copied to the offset"
+			);
+
+			assert!(dst.validate(), "length should match the set bits");
+		}
+	}
+
+	#[test]
+	fn ascending_snapshot() {
+		let mut frontier: Owned = [0, 1].into_iter().collect();
+
+		for value in frontier.clone().ascending_owned() {
+			frontier.grow_insert(value + 100);
+		}
+
+		assert!(
+			frontier.ascending().eq([0, 1, 100, 101]),
+			"snapshot should not see values inserted later"
+		);
+	}
+
+	#[test]
+	fn cardinality_order() {
+		use core::cmp::Ordering;
+
+		let small: Owned = [50].into_iter().collect();
+		let large: Owned = [0, 1].into_iter().collect();
+		let other: Owned = [0, 2].into_iter().collect();
+
+		assert_eq!(
+			small.as_slice().cmp_by_cardinality(large.as_slice()),
+			Ordering::Less,
+			"fewer values should order as less"
+		);
+
+		assert_eq!(
+			large.as_slice().cmp_by_cardinality(other.as_slice()),
+			Ordering::Less,
+			"fewer values should order as less regardless of magnitude"
+		);
+
+		assert_eq!(
+			large.as_slice().cmp_by_cardinality(large.as_slice()),
+			Ordering::Equal,
+			"same cardinality should order as equal"
+		);
+	}
+
+	#[test]
+	fn eq_lazy_iter() {
+		let set: Owned = (0..300).step_by(3).collect();
+
+		assert!(
+			set.as_slice().eq_iter((0..300).step_by(3)),
+			"matching values should be equal"
+		);
+
+		assert!(
+			!set.as_slice().eq_iter((0..297).step_by(3)),
+			"missing values should not be equal"
+		);
+
+		assert!(
+			!set.as_slice().eq_iter((0..303).step_by(3)),
+			"extra values should not be equal"
+		);
+
+		assert!(
+			!set.as_slice().eq_iter([0, 4]),
+			"differing values should not be equal"
+		);
+	}
+
+	#[test]
+	fn ascending_skip_run() {
+		let mut set: Owned = [200].into_iter().collect();
+
+		set.grow_insert_all(10, 100);
+
+		let mut ascending = set.ascending();
+
+		assert_eq!(
+			ascending.next(),
+			Some(10),
+			"first value should be yielded alone"
+		);
+
+		assert_eq!(
+			ascending.skip_run(),
+			89,
+			"skip should count the rest of the run"
+		);
+
+		assert_eq!(ascending.len(), 1, "length should count the values left");
+		assert_eq!(ascending.skip_run(), 1, "single value should form a run");
+		assert_eq!(
+			ascending.skip_run(),
+			0,
+			"exhausted iterator should skip nothing"
+		);
+	}
+
+	#[test]
+	fn builder() {
+		let mut builder = crate::SetBuilder::new();
+
+		assert!(
+			!builder.insert(500),
+			"absent value should report being absent"
+		);
+
+		assert!(
+			builder.insert(500),
+			"present value should report being present"
+		);
+
+		builder.insert_all(60, 70);
+		builder.extend([3, 65]);
+
+		assert_eq!(builder.len(), 12, "length should count distinct values");
+
+		let set = builder.build();
+
+		assert_eq!(set.len(), 12, "built set should keep the length");
+		assert_eq!(
+			set.chunk_capacity(),
+			8,
+			"built set should keep the builder allocation"
+		);
+
+		assert!(set.validate(), "length should match the set bits");
+	}
+
+	#[test]
+	fn common_prefix() {
+		let left: Owned = [1, 2, 70, 80, 300].into_iter().collect();
+		let right: Owned = [1, 2, 70, 81].into_iter().collect();
+
+		assert_eq!(
+			left.as_slice().common_prefix_len(right.as_slice()),
+			3,
+			"prefix should stop at the first difference"
+		);
+
+		assert_eq!(
+			left.as_slice().common_prefix_len(left.as_slice()),
+			5,
+			"set should share its whole length with itself"
+		);
+
+		assert_eq!(
+			left.as_slice().common_prefix_len(Owned::new().as_slice()),
+			0,
+			"empty set should share no prefix"
+		);
+	}
+
+	#[test]
+	fn remove_all_changed() {
+		let mut set: Owned = [5, 70, 130].into_iter().collect();
+
+		assert_eq!(
+			set.remove_all_changed(6, 70),
+			Some(false),
+			"removing absent values should report no change"
+		);
+
+		assert_eq!(
+			set.remove_all_changed(6, 131),
+			Some(true),
+			"removing present values should report a change"
+		);
+
+		assert_eq!(
+			set.remove_all_changed(0, 10_000),
+			None,
+			"range beyond the maximum should be rejected"
+		);
+
+		assert!(
+			set.ascending().eq([5]),
+			"values outside the range should be kept"
+		);
+	}
+
+	#[test]
+	fn reset_to_range() {
+		let mut set: Owned = [1, 300].into_iter().collect();
+
+		set.reset_to_range(60, 130);
+
+		assert!(
+			set.ascending().eq(60..130),
+			"set should hold exactly the range"
+		);
+
+		assert_eq!(set.len(), 70, "length should count the range");
+
+		set.reset_to_range(10, 10);
+
+		assert!(set.is_empty(), "empty range should clear the set");
+		assert!(set.validate(), "length should match the set bits");
+	}
+
+	#[test]
+	fn empty_chunk_bookends() {
+		let mut set = Owned::with_maximum(640);
+
+		assert_eq!(
+			set.as_slice().leading_empty_chunks(),
+			10,
+			"empty chunks before the values should be counted"
+		);
+
+		assert_eq!(
+			set.as_slice().trailing_empty_chunks(),
+			10,
+			"empty chunks after the values should be counted"
+		);
+
+		set.insert(130).unwrap();
+		set.insert(200).unwrap();
+
+		assert_eq!(
+			set.as_slice().leading_empty_chunks(),
+			2,
+			"leading chunks should stop at the first value"
+		);
+
+		assert_eq!(
+			set.as_slice().trailing_empty_chunks(),
+			6,
+			"trailing chunks should stop at the last value"
+		);
+	}
+
+	#[test]
+	fn merge_join() {
+		use crate::MergeSide;
+
+		let left: Owned = [1, 2, 70].into_iter().collect();
+		let right: Owned = [2, 300].into_iter().collect();
+
+		assert!(
+			left.as_slice().merge_join(right.as_slice()).eq([
+				(1, MergeSide::Left),
+				(2, MergeSide::Both),
+				(70, MergeSide::Left),
+				(300, MergeSide::Right),
+			]),
+			"join should tag every value by its sets"
+		);
+
+		let left: Owned = [63, 64, 127].into_iter().collect();
+		let right: Owned = [0, 64, 128].into_iter().collect();
+
+		assert!(
+			left.as_slice().merge_join(right.as_slice()).eq([
+				(0, MergeSide::Right),
+				(63, MergeSide::Left),
+				(64, MergeSide::Both),
+				(127, MergeSide::Left),
+				(128, MergeSide::Right),
+			]),
+			"join should tag values across chunk boundaries and past the shorter set"
+		);
+
+		assert!(
+			Owned::new()
+				.as_slice()
+				.merge_join(left.as_slice())
+				.all(|(_, side)| side == MergeSide::Right),
+			"join with an empty set should tag only the other side"
+		);
+	}
+
+	#[test]
+	fn toggle_all_delta() {
+		let mut set: Owned = [1, 2, 3, 100].into_iter().collect();
+
+		assert_eq!(
+			set.toggle_all_delta(0, 4),
+			Some(-2),
+			"delta should be the change in length"
+		);
+
+		assert!(set.ascending().eq([0, 100]), "toggle should flip the range");
+		assert_eq!(
+			set.toggle_all_delta(64, 128),
+			Some(62),
+			"delta should count the values added"
+		);
+
+		assert_eq!(set.len(), 64, "length should include the delta");
+		assert_eq!(
+			set.toggle_all_delta(0, 1000),
+			None,
+			"range beyond the maximum should be rejected"
+		);
+	}
+
+	#[test]
+	fn contains_sorted_queries() {
+		let set: Owned = [3, 64, 65, 900].into_iter().collect();
+		let found = set
+			.as_slice()
+			.contains_sorted(&[0, 3, 63, 64, 65, 899, 900, 5000]);
+
+		assert_eq!(
+			found,
+			[false, true, false, true, true, false, true, false],
+			"each query should report its presence"
+		);
+	}
+
+	#[test]
+	fn remove_lowest() {
+		let mut set: Owned = [70, 200, 201].into_iter().collect();
+
+		assert_eq!(
+			set.remove_min(),
+			Some(70),
+			"smallest value should be removed"
+		);
+
+		set.clear_lowest();
+
+		assert!(set.ascending().eq([201]), "larger values should be kept");
+		assert_eq!(
+			set.remove_min(),
+			Some(201),
+			"next smallest value should be removed"
+		);
+
+		assert_eq!(set.remove_min(), None, "empty set should have no minimum");
+
+		set.clear_lowest();
+
+		assert!(
+			set.is_empty(),
+			"set should be empty after removing every value"
+		);
+	}
+
+	#[test]
+	fn layout_info() {
+		use crate::layout::{BitOrder, LayoutInfo};
+
+		let set: Owned = [0, 65].into_iter().collect();
+
+		assert_eq!(
+			set.as_slice().layout_info(),
+			LayoutInfo {
+				chunk_bits: 64,
+				bit_order: BitOrder::Lsb0,
+				chunk_count: set.chunk_capacity(),
+			},
+			"layout should describe the backing words"
+		);
+
+		assert_eq!(
+			set.as_words()[..2],
+			[1, 2],
+			"words should be stored least significant bit first"
+		);
+	}
+
+	#[test]
+	fn remove_chunk_block() {
+		let mut set: Owned = [1, 64, 100, 128, 300].into_iter().collect();
+
+		assert_eq!(
+			set.remove_chunk_block(1, 3),
+			3,
+			"block removal should count the values removed"
+		);
+
+		assert!(
+			set.ascending().eq([1, 300]),
+			"values outside the block should be kept"
+		);
+
+		assert_eq!(
+			set.remove_chunk_block(4, 100),
+			1,
+			"block past the end should be clamped"
+		);
+
+		assert_eq!(
+			set.remove_chunk_block(50, 100),
+			0,
+			"block beyond the chunks should remove nothing"
+		);
+
+		assert_eq!(set.len(), 1, "length should count the values left");
+	}
+
+	#[test]
+	fn small_len() {
+		assert_eq!(
+			Owned::new().as_slice().small_len(),
+			Some(0),
+			"empty set should have a small length"
+		);
+
+		assert_eq!(
+			Owned::full(255).as_slice().small_len(),
+			Some(255),
+			"length below 256 should be small"
+		);
+
+		assert_eq!(
+			Owned::full(256).as_slice().small_len(),
+			None,
+			"length of 256 should not be small"
+		);
+	}
+
+	#[test]
+	fn fingerprint() {
+		let left: Owned = [1, 64].into_iter().collect();
+		let mut right = Owned::with_maximum(1000);
+
+		right.extend([1, 64]);
+
+		assert_eq!(
+			left.as_slice().fingerprint(),
+			right.as_slice().fingerprint(),
+			"equal sets should have equal fingerprints"
+		);
+
+		right.insert(999).unwrap();
+
+		assert_ne!(
+			left.as_slice().fingerprint(),
+			right.as_slice().fingerprint(),
+			"differing sets should have differing fingerprints"
+		);
+	}
+
+	#[test]
+	fn retain_collect() {
+		let mut set: Owned = (0..200).collect();
+
+		assert_eq!(
+			set.retain_collect(|value| value % 3 == 0),
+			133,
+			"retain should count the values removed"
+		);
+
+		assert!(
+			set.ascending().eq((0..200).step_by(3)),
+			"retain should keep the accepted values"
+		);
+
+		assert!(set.validate(), "length should match the set bits");
+	}
+
+	#[test]
+	fn msb_bytes_round_trip() {
+		let set = Owned::from_iter([0, 7, 9, 70]);
+		let bytes = set.as_slice().to_msb_bytes();
+
+		assert_eq!(
+			&bytes[..2],
+			&[0b1000_0001, 0b0100_0000],
+			"values should be packed most significant bit first"
+		);
+
+		assert_eq!(bytes.len(), 9, "trailing zero bytes should be omitted");
+		assert_eq!(
+			Owned::from_msb_bytes(&bytes),
+			set,
+			"bytes should decode to the original set"
+		);
+	}
+
+	#[test]
+	fn reserve_like() {
+		let other = Owned::from_iter([5, 300]);
+		let mut set = Owned::from_iter([1]);
+
+		set.reserve_like(other.as_slice());
+
+		let capacity = set.chunk_capacity();
+
+		assert_eq!(capacity, 5, "set should fit the largest value of `other`");
+
+		set.grow_union_with(other.as_slice());
+
+		assert_eq!(
+			set.chunk_capacity(),
+			capacity,
+			"union should not reallocate after reserving"
+		);
+
+		assert!(
+			set.ascending().eq([1, 5, 300]),
+			"union should hold the values of both sets"
+		);
+
+		let mut padded = Owned::with_maximum(1000);
+		let mut set = Owned::new();
+
+		padded.insert(70);
+		set.reserve_like(padded.as_slice());
+
+		assert_eq!(
+			set.chunk_capacity(),
+			2,
+			"trailing empty chunks of `other` should not be reserved"
+		);
+	}
+
+	#[test]
+	fn debug_adaptors() {
+		let set: Owned = (0..20).chain(100..110).collect();
+		let full = alloc::format!("{:?}", set.debug_full());
+		let stats = alloc::format!("{:?}", set.debug_stats());
+
+		assert_eq!(
+			full.matches(',').count(),
+			29,
+			"full listing should print every value"
+		);
+
+		assert_eq!(
+			stats, "Stats { len: 30, maximum: 128, occupied_chunks: 2, run_count: 2 }",
+			"stats should describe the set"
+		);
+	}
+
+	#[test]
+	fn mex_of_union() {
+		let lhs: Owned = (0..64).chain(65..70).collect();
+		let rhs = Owned::from_iter([64, 70, 72]);
+
+		assert_eq!(
+			Owned::mex_of_union(&[]),
+			0,
+			"union of no sets should miss 0"
+		);
+
+		assert_eq!(
+			Owned::mex_of_union(&[lhs.as_slice()]),
+			64,
+			"mex should be the first missing value"
+		);
+
+		assert_eq!(
+			Owned::mex_of_union(&[lhs.as_slice(), rhs.as_slice()]),
+			71,
+			"mex should skip values of every set"
+		);
+
+		assert_eq!(
+			Owned::mex_of_union(&[Owned::full(64).as_slice()]),
+			64,
+			"mex of a full chunk should be the next chunk"
+		);
+	}
+
+	#[test]
+	fn from_range() {
+		let set = Owned::from(70..200);
+
+		assert_eq!(set.len(), 130, "length should count the range");
+		assert_eq!(
+			set.as_words().len(),
+			4,
+			"range should allocate only the chunks it needs"
+		);
+
+		assert!(set.ascending().eq(70..200), "set should hold the range");
+		assert!(
+			Owned::from(5..5).is_empty(),
+			"empty range should give an empty set"
+		);
+	}
+
+	#[test]
+	fn chunks_with_base() {
+		let set = Owned::from_iter([1, 130]);
+		let all: Vec<_> = set.as_slice().chunks_with_base().collect();
+		let nonzero: Vec<_> = set.as_slice().chunks_nonzero().collect();
+
+		assert_eq!(
+			all,
+			[(0, 0b10), (64, 0), (128, 0b100)],
+			"every chunk should be paired with its base"
+		);
+
+		assert_eq!(
+			nonzero,
+			[(0, 0b10), (128, 0b100)],
+			"only non-zero chunks should be yielded"
+		);
+	}
+
+	#[test]
+	fn slice_range_window() {
+		let set = Owned::from_iter([3, 64, 100, 200]);
+		let window = set.slice_range(1, 3);
+
+		assert_eq!(window.len(), 2, "window should count only its values");
+		assert_eq!(
+			window.ascending().len(),
+			2,
+			"window iterator should be exact sized"
+		);
+
+		assert!(
+			window.ascending().eq([0, 36]),
+			"window values should be offset by its first chunk"
+		);
+	}
+
+	#[test]
+	fn contains_i64() {
+		let set = Owned::from_iter([0, 70]);
+
+		assert!(
+			set.as_slice().contains_i64(70),
+			"present value should be contained"
+		);
+
+		assert!(
+			!set.as_slice().contains_i64(-1),
+			"negative value should not be contained"
+		);
+
+		assert!(
+			!set.as_slice().contains_i64(i64::MIN),
+			"minimum value should not be contained"
+		);
+
+		assert!(
+			!set.as_slice().contains_i64(i64::MAX),
+			"value beyond the maximum should not be contained"
+		);
+	}
+
+	#[test]
+	fn drain() {
+		let mut set = Owned::from_iter([1, 64, 90, 300]);
+		let mut drain = set.drain();
+
+		assert_eq!(drain.len(), 4, "drain should be exact sized");
+		assert_eq!(
+			drain.next(),
+			Some(1),
+			"drain should yield the smallest value first"
+		);
+
+		assert_eq!(
+			drain.next(),
+			Some(64),
+			"drain should yield values in ascending order"
+		);
+
+		assert_eq!(
+			drain.len(),
+			2,
+			"drain length should shrink as values are taken"
+		);
+
+		drop(drain);
+
+		assert!(set.is_empty(), "dropped drain should empty the set");
+		assert!(set.validate(), "length should match the set bits");
+
+		set.insert_all(0, 10);
+
+		assert!(set.drain().eq(0..10), "drain should yield every value");
+		assert!(set.is_empty(), "consumed drain should empty the set");
+	}
+
+	#[test]
+	fn insert_ranked() {
+		let mut set = Owned::from_iter([2, 70]);
+
+		assert_eq!(
+			set.insert_ranked(100),
+			(false, 2),
+			"rank should count the smaller values"
+		);
+
+		assert_eq!(
+			set.insert_ranked(70),
+			(true, 1),
+			"present value should report being present"
+		);
+
+		assert_eq!(
+			set.insert_ranked(0),
+			(false, 0),
+			"smallest value should have rank 0"
+		);
+
+		assert_eq!(set.len(), 4, "length should count the inserted values");
+	}
+
+	#[test]
+	fn intersects_in_range() {
+		let lhs = Owned::from_iter([5, 100, 200]);
+		let rhs = Owned::from_iter([5, 101, 200]);
+		let (lhs, rhs) = (lhs.as_slice(), rhs.as_slice());
+
+		assert!(
+			lhs.intersects_in_range(rhs, 0, 6),
+			"common value in the range should intersect"
+		);
+
+		assert!(
+			!lhs.intersects_in_range(rhs, 6, 200),
+			"range without common values should not intersect"
+		);
+
+		assert!(
+			lhs.intersects_in_range(rhs, 6, 201),
+			"range ending after a common value should intersect"
+		);
+
+		assert!(
+			!lhs.intersects_in_range(rhs, 201, 1000),
+			"range past the values should not intersect"
+		);
+	}
+
+	#[test]
+	fn reuse() {
+		let mut set = Owned::from_iter([1]);
+
+		set.reuse(Owned::from_iter([5, 1000]));
+
+		assert!(set.is_empty(), "set should be empty after reuse");
+		assert!(set.validate(), "length should match the set bits");
+		assert_eq!(set.maximum(), 1024, "set should take over the buffer");
+	}
+
+	#[test]
+	fn capacity_boundary() {
+		let mut set = Owned::with_maximum(128);
+
+		assert_eq!(
+			set.maximum(),
+			128,
+			"maximum should cover the allocated chunks"
+		);
+
+		assert_eq!(set.insert(127), Some(false), "last value should fit");
+		assert!(set.contains(127), "last value should be contained");
+		assert_eq!(set.remove(127), Some(true), "last value should be removed");
+		assert_eq!(set.insert(128), None, "value at the maximum should not fit");
+		assert_eq!(
+			set.remove(128),
+			None,
+			"value at the maximum should not be removed"
+		);
+
+		assert!(
+			!set.contains(128),
+			"value at the maximum should not be contained"
+		);
+
+		assert_eq!(
+			set.as_slice().get(128),
+			None,
+			"value at the maximum should be out of bounds"
+		);
+
+		assert_eq!(
+			set.insert_all(0, 128),
+			Some(()),
+			"range up to the maximum should fit"
+		);
+
+		assert_eq!(
+			set.insert_all(0, 129),
+			None,
+			"range past the maximum should be rejected"
+		);
+
+		assert_eq!(set.len(), 128, "length should count the range");
+		assert!(set.validate(), "length should match the set bits");
+	}
+
+	#[test]
+	fn select_back() {
+		let set = Owned::from_iter([1, 64, 90, 300]);
+
+		for n in 0..4 {
+			assert_eq!(
+				set.as_slice().select_back(n),
+				set.descending().nth(n),
+				"select should match the descending order"
+			);
+		}
+
+		assert_eq!(
+			set.as_slice().select_back(4),
+			None,
+			"select past the length should return nothing"
+		);
+	}
+
+	#[test]
+	fn retain_mask_chunk() {
+		let mut set: Owned = (0..128).collect();
+
+		set.retain_mask_chunk(1, 0b1010);
+		set.retain_mask_chunk(5, 0);
+
+		assert_eq!(set.len(), 66, "length should count the masked values");
+		assert!(
+			set.ascending().eq((0..64).chain([65, 67])),
+			"mask should only affect its chunk"
+		);
+	}
+
+	#[test]
+	fn extend_vec() {
+		let mut values = Vec::from([7]);
+
+		Owned::from_iter([1, 90]).as_slice().extend_vec(&mut values);
+
+		assert_eq!(
+			values,
+			[7, 1, 90],
+			"values should be appended after the existing ones"
+		);
+	}
+
+	#[test]
+	fn count_in_range_of() {
+		let lhs: Owned = (0..200).step_by(2).collect();
+		let rhs: Owned = (0..300).step_by(3).collect();
+		let (lhs, rhs) = (lhs.as_slice(), rhs.as_slice());
+
+		assert_eq!(
+			lhs.count_in_range_of(rhs, 10, 130),
+			(12..130).step_by(6).count(),
+			"count should only include the common values in the range"
+		);
+
+		assert_eq!(
+			lhs.count_in_range_of(rhs, 0, 1000),
+			(0..200).step_by(6).count(),
+			"count should stop at the last common value"
+		);
+
+		assert_eq!(
+			lhs.count_in_range_of(rhs, 50, 50),
+			0,
+			"empty range should count nothing"
+		);
+	}
+
+	#[test]
+	fn insert_strict() {
+		let mut set = Owned::with_maximum(64);
+
+		assert!(
+			!set.insert_strict(63),
+			"absent value should report being absent"
+		);
+
+		assert!(
+			set.insert_strict(63),
+			"present value should report being present"
+		);
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic = "should be below the maximum"]
+	fn insert_strict_out_of_range() {
+		Owned::with_maximum(64).insert_strict(64);
+	}
+
+	#[test]
+	#[cfg(feature = "morton")]
+	fn from_morton_2d() {
+		let set = Owned::from_morton_2d(&[(0, 0), (1, 0), (0, 1), (3, 3), (2, 5)]);
+
+		assert!(
+			set.ascending().eq([0, 1, 2, 15, 38]),
+			"points should be stored by their Z-order code"
+		);
+	}
+
+	#[test]
+	fn transition_count() {
+		let set: Owned = (0..10).chain(60..70).chain([127]).collect();
+
+		assert_eq!(
+			set.as_slice().transition_count(),
+			4,
+			"every edge should be counted"
+		);
+
+		assert_eq!(
+			Owned::full(128).as_slice().transition_count(),
+			0,
+			"full set should have no transitions"
+		);
+
+		assert_eq!(
+			Owned::from_iter([64]).as_slice().transition_count(),
+			2,
+			"single value should have two transitions"
+		);
+	}
+
+	#[test]
+	fn from_chunks() {
+		let set = Owned::from_chunks([0b101, 0, 1]);
+
+		assert_eq!(
+			set.len(),
+			3,
+			"length should count the values of every chunk"
+		);
+
+		assert!(
+			set.ascending().eq([0, 2, 128]),
+			"values should be read from the chunks"
+		);
+	}
+
+	#[test]
+	fn contains_inclusive() {
+		let set: Owned = (10..=130).collect();
+		let set = set.as_slice();
+
+		assert!(
+			set.contains_inclusive(10..=130),
+			"whole range should be contained"
+		);
+
+		assert!(
+			set.contains_inclusive(64..=127),
+			"range within the set should be contained"
+		);
+
+		assert!(
+			!set.contains_inclusive(9..=20),
+			"range starting before the set should not be contained"
+		);
+
+		assert!(
+			!set.contains_inclusive(100..=131),
+			"range ending after the set should not be contained"
+		);
+
+		assert!(
+			!set.contains_inclusive(100..=usize::MAX),
+			"range up to the largest value should not overflow"
+		);
+
+		assert!(
+			set.contains_inclusive(core::ops::RangeInclusive::new(1, 0)),
+			"empty range should be contained"
+		);
+	}
+
+	#[test]
+	fn density() {
+		let set: Owned = (0..64).chain([64, 65]).collect();
+		let histogram = set.as_slice().chunk_density_histogram();
+
+		assert!(
+			(set.as_slice().density() - 66.0 / 128.0).abs() < f64::EPSILON,
+			"density should be the fraction of values present"
+		);
+
+		assert!(
+			Owned::new().as_slice().density() == 0.0,
+			"set without chunks should have no density"
+		);
+
+		assert_eq!(
+			(histogram[2], histogram[64]),
+			(1, 1),
+			"chunks should be counted by their values"
+		);
+
+		assert_eq!(
+			histogram.iter().sum::<usize>(),
+			2,
+			"histogram should count every chunk"
+		);
+	}
+
+	#[test]
+	fn keep_lowest_and_highest() {
+		let source = Owned::from_iter([1, 64, 90, 300]);
+		let mut set = source.clone();
+
+		set.keep_lowest(2);
+		assert!(set.ascending().eq([1, 64]), "lowest values should be kept");
+
+		set.keep_lowest(5);
+		assert_eq!(
+			set.len(),
+			2,
+			"keeping more than the length should change nothing"
+		);
+
+		let mut set = source.clone();
+
+		set.keep_highest(3);
+		assert!(
+			set.ascending().eq([64, 90, 300]),
+			"highest values should be kept"
+		);
+
+		set.keep_highest(0);
+		assert!(set.is_empty(), "keeping no values should empty the set");
+		assert_eq!(
+			source.as_slice().select(2),
+			Some(90),
+			"select should find the value by rank"
+		);
+	}
+
+	#[test]
+	fn eq_including_capacity() {
+		let lhs = Owned::from_iter([1, 2]);
+		let mut rhs = lhs.clone();
+
+		assert!(
+			lhs.eq_including_capacity(&rhs),
+			"clones should have the same layout"
+		);
+
+		rhs.reserve_exact_chunks(2);
+
+		assert_eq!(lhs, rhs, "trailing empty chunks should not affect equality");
+		assert!(
+			!lhs.eq_including_capacity(&rhs),
+			"extra chunks should differ in layout"
+		);
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic = "is `len` miscounted?"]
+	fn ascending_miscounted_len() {
+		let data = [0b1, 0];
+
+		// SAFETY: The bounds check panics before reading past the chunks.
+		let iter = unsafe { crate::ascending::Ascending::new(&data, 2) };
+
+		iter.for_each(drop);
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic = "is `len` miscounted?"]
+	fn descending_miscounted_len() {
+		let data = [0, 0b1];
+
+		// SAFETY: The bounds check panics before reading past the chunks.
+		let iter = unsafe { crate::descending::Descending::new(&data, 2) };
+
+		iter.for_each(drop);
+	}
+
+	#[test]
+	fn set_macro() {
+		let empty: Owned = crate::set![];
+		let mixed = crate::set![1, 3, 5..9, 200];
+
+		assert!(empty.is_empty(), "empty macro should give an empty set");
+		assert!(
+			mixed.ascending().eq([1, 3, 5, 6, 7, 8, 200]),
+			"macro should hold values and ranges"
+		);
+
+		assert_eq!(
+			mixed.maximum(),
+			256,
+			"macro should allocate once for the largest value"
+		);
+
+		assert!(
+			crate::set![0..10].ascending().eq(0..10),
+			"macro should fill a range"
+		);
+	}
+
+	#[test]
+	fn run_length_at() {
+		let set: Owned = (5..10).chain(60..200).chain([255]).collect();
+		let set = set.as_slice();
+
+		assert_eq!(
+			set.run_length_at(7),
+			5,
+			"run should include values on both sides"
+		);
+
+		assert_eq!(
+			set.run_length_at(5),
+			5,
+			"run should include values from its start"
+		);
+
+		assert_eq!(
+			set.run_length_at(60),
+			140,
+			"run should continue across chunks"
+		);
+
+		assert_eq!(
+			set.run_length_at(130),
+			140,
+			"run should include values before the seam"
+		);
+
+		assert_eq!(set.run_length_at(255), 1, "single value should form a run");
+		assert_eq!(set.run_length_at(10), 0, "absent value should have no run");
+		assert_eq!(
+			set.run_length_at(1000),
+			0,
+			"value beyond the maximum should have no run"
+		);
+
+		assert_eq!(
+			Owned::full(192).as_slice().run_length_at(100),
+			192,
+			"run should span every full chunk"
+		);
+	}
+
+	#[test]
+	fn difference_runs() {
+		let lhs: Owned = (0..200).collect();
+		let rhs: Owned = (10..60).chain([128]).collect();
+		let runs: Vec<_> = lhs.as_slice().difference_runs(rhs.as_slice()).collect();
+
+		assert_eq!(
+			runs,
+			[(0, 10), (60, 128), (129, 200)],
+			"runs should only cover the values missing from the other set"
+		);
+	}
+
+	#[test]
+	fn is_subset_within() {
+		use crate::SubsetResult;
+
+		let lhs: Owned = (0..10).chain([100, 200]).collect();
+		let rhs: Owned = (0..10).collect();
+		let (lhs, rhs) = (lhs.as_slice(), rhs.as_slice());
+
+		assert_eq!(
+			rhs.is_subset_within(lhs, 0),
+			SubsetResult::Missing(0),
+			"subset should miss no values"
+		);
+
+		assert_eq!(
+			lhs.is_subset_within(rhs, 2),
+			SubsetResult::Missing(2),
+			"exact miss count should be returned within the limit"
+		);
+
+		assert_eq!(
+			lhs.is_subset_within(rhs, 1),
+			SubsetResult::TooMany,
+			"misses beyond the limit should stop early"
+		);
+	}
+
+	#[test]
+	fn rotate() {
+		let mut set = Owned::from_iter([0, 5, 99, 150]);
+
+		set.rotate(3, 100);
+		assert!(
+			set.ascending().eq([2, 3, 8, 150]),
+			"values should rotate within the modulus"
+		);
+
+		set.rotate(200, 100);
+		assert!(
+			set.ascending().eq([2, 3, 8, 150]),
+			"full turns should leave the set unchanged"
+		);
+
+		let mut set = Owned::from_iter([1]);
+
+		set.rotate(300, 1000);
+		assert!(
+			set.ascending().eq([301]),
+			"set should grow to fit rotated values"
+		);
+
+		assert!(set.validate(), "length should match the set bits");
+	}
+
+	#[test]
+	fn into_sorted_boxed() {
+		let values = Owned::from_iter([90, 1, 64]).into_sorted_boxed();
+
+		assert_eq!(
+			&*values,
+			&[1, 64, 90],
+			"values should be returned in ascending order"
+		);
+	}
+
+	#[test]
+	fn try_for_each_value() {
+		let set = Owned::from_iter([1, 64, 90, 300]);
+		let mut seen = Vec::new();
+
+		let result = set.as_slice().try_for_each_value(|value| {
+			seen.push(value);
+
+			if value > 80 {
+				Err(value)
+			} else {
+				Ok(())
+			}
+		});
+
+		assert_eq!(result, Err(90), "first error should be returned");
+		assert_eq!(seen, [1, 64, 90], "visit should stop at the first error");
+
+		let mut total = 0;
+
+		set.as_slice().for_each_value(|value| total += value);
+
+		assert_eq!(total, 455, "every value should be visited");
+	}
+
+	#[test]
+	fn chunk_bounds() {
+		let mut set = Owned::from_iter([100, 300]);
+
+		set.reserve_exact_chunks(8);
+
+		assert_eq!(
+			set.as_slice().chunk_bounds(),
+			Some((1, 5)),
+			"bounds should span the non-empty chunks"
+		);
+
+		assert_eq!(
+			Owned::with_maximum(256).as_slice().chunk_bounds(),
+			None,
+			"empty set should have no chunk bounds"
+		);
+	}
+
+	#[test]
+	fn union_extend() {
+		let shards = [
+			Owned::from_iter([1]),
+			Owned::from_iter([70]),
+			Owned::from_iter([1, 300]),
+		];
+		let mut set = Owned::new();
+
+		set.union_extend(shards.iter().map(Owned::as_slice));
+
+		assert!(
+			set.ascending().eq([1, 70, 300]),
+			"union should hold the values of every slice"
+		);
+
+		assert_eq!(set.len(), 3, "length should count shared values once");
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic = "`len` should not exceed the number of set bits"]
+	fn slice_miscounted_len() {
+		let data = [0b1, 0];
+
+		// SAFETY: The debug assertion panics before the length is used.
+		let set = unsafe { Borrowed::new(&data, 2) };
+
+		set.descending().for_each(drop);
+	}
+
+	#[test]
+	fn snapshot_diff() {
+		let previous = Owned::from_iter([1, 64, 90]);
+		let current = Owned::from_iter([1, 90, 300]);
+		let (previous, current) = (previous.as_slice(), current.as_slice());
+
+		assert!(
+			current.added_since(previous).eq([300]),
+			"added values should be yielded"
+		);
+
+		assert!(
+			current.removed_since(previous).eq([64]),
+			"removed values should be yielded"
+		);
+
+		assert!(
+			current.added_since(previous).size_hint().1 >= Some(1),
+			"bound should cover the added values"
+		);
+	}
+
+	#[test]
+	fn grow_maximum_saturating() {
+		let mut set = Owned::new();
+
+		assert!(
+			!set.grow_maximum_saturating(1000),
+			"small growth should not be clamped"
+		);
+
+		assert!(
+			set.maximum() >= 1000,
+			"growth should fit the requested values"
+		);
+
+		assert_eq!(
+			super::saturating_chunks(2, usize::MAX),
+			(Owned::MAX_CHUNKS, true),
+			"huge growth should be clamped to the cap"
+		);
+
+		assert_eq!(
+			super::saturating_chunks(Owned::MAX_CHUNKS + 1, usize::MAX),
+			(Owned::MAX_CHUNKS + 1, true),
+			"set beyond the cap should not grow"
+		);
+
+		assert_eq!(
+			super::saturating_chunks(4, 64 * 5),
+			(8, false),
+			"growth below the cap should double"
+		);
+
+		assert_eq!(
+			super::saturating_chunks(4, 64 * 3),
+			(4, false),
+			"values that fit should not grow the set"
+		);
+	}
+
+	#[test]
+	fn from_bits() {
+		let set = Owned::from_bits(0b1001 | 1 << 63, 100);
+
+		assert_eq!(set.len(), 3, "length should count the set bits");
+		assert!(
+			set.ascending().eq([100, 103, 163]),
+			"values should be offset by the base"
+		);
+
+		assert!(set.maximum() >= 164, "set should fit the whole word");
+		assert!(
+			Owned::from_bits(0, 0).is_empty(),
+			"no bits should give an empty set"
+		);
+	}
+
+	#[test]
+	fn take_run() {
+		let mut set: Owned = (5..10).chain(60..200).collect();
+
+		assert_eq!(
+			set.take_run(100),
+			Some((60, 200)),
+			"run should be removed whole"
+		);
+
+		assert_eq!(
+			set.take_run(100),
+			None,
+			"removed run should not be taken again"
+		);
+
+		assert_eq!(
+			set.take_run(5000),
+			None,
+			"value beyond the maximum should have no run"
+		);
+
+		assert!(set.ascending().eq(5..10), "other runs should be kept");
+		assert!(set.validate(), "length should match the set bits");
+	}
+
+	#[test]
+	fn compare_counts() {
+		let lhs: Owned = (0..100).collect();
+		let rhs: Owned = (50..300).collect();
+
+		assert_eq!(
+			lhs.as_slice().compare_counts(rhs.as_slice()),
+			(50, 50, 200),
+			"counts should split the union"
+		);
+	}
+
+	#[test]
+	fn chunk_groups() {
+		let set = Owned::from_iter([1, 3, 130, 140]);
+		let groups: Vec<(usize, Vec<usize>)> = set
+			.as_slice()
+			.chunk_groups()
+			.map(|(base, values)| (base, values.collect()))
+			.collect();
+
+		assert_eq!(
+			groups,
+			[(0, Vec::from([1, 3])), (128, Vec::from([130, 140]))],
+			"values should be grouped by chunk"
+		);
+	}
+
+	#[test]
+	fn range_runs_stop_at_end() {
+		let set: Owned = (0..200).collect();
+
+		assert_eq!(
+			set.as_slice().range(0, 10).next_run(),
+			Some((0, 10)),
+			"run should stop at the end of the range"
+		);
+
+		assert_eq!(
+			set.as_slice().range(5, 70).next_run(),
+			Some((5, 70)),
+			"run should stop at the end of the range across chunks"
+		);
+
+		assert_eq!(
+			set.as_slice().range(0, 10).skip_run(),
+			10,
+			"skipped run should stop at the end of the range"
+		);
+
+		let mut range = set.as_slice().range(60, 130);
+
+		assert_eq!(
+			range.next_run(),
+			Some((60, 130)),
+			"run should span the range"
+		);
+
+		assert_eq!(range.next_run(), None, "range should be exhausted");
+	}
+
+	#[test]
+	fn runs_bytes_oversized() {
+		let mut bytes = Vec::from([0x00]);
+
+		super::write_varint(&mut bytes, 1 << 60);
+
+		assert_eq!(
+			Owned::from_runs_bytes(&bytes),
+			None,
+			"oversized run should be rejected"
+		);
+	}
+
+	#[test]
+	fn truncate_at() {
+		let mut set: Owned = [1, 63, 64, 100, 200].into_iter().collect();
+		let capacity = set.chunk_capacity();
+
+		set.truncate_at(64);
+
+		assert!(
+			set.ascending().eq([1, 63]),
+			"values at or above 64 should be removed"
+		);
+		assert!(set.validate(), "length should match the set bits");
+		assert_eq!(set.chunk_capacity(), capacity, "capacity should be kept");
+
+		set.truncate_at(63);
+
+		assert!(set.ascending().eq([1]), "boundary value should be removed");
+
+		set.truncate_at(10_000);
+
+		assert_eq!(
+			set.len(),
+			1,
+			"threshold past the maximum should remove nothing"
+		);
+	}
+
+	#[test]
+	fn symmetric_difference_into() {
+		let left: Owned = [1, 63, 64, 300].into_iter().collect();
+		let right: Owned = [1, 64, 65].into_iter().collect();
+		let mut dst: Owned = (0..1000).collect();
+
+		left.as_slice()
+			.symmetric_difference_into(right.as_slice(), &mut dst);
+
+		assert!(
+			dst.ascending().eq([63, 65, 300]),
+			"destination should hold the values in exactly one set"
+		);
+
+		assert!(dst.validate(), "length should match the set bits");
+	}
+
+	#[test]
+	fn combinations_into() {
+		let left: Owned = [1, 63, 64, 300].into_iter().collect();
+		let right: Owned = [1, 64, 65].into_iter().collect();
+		let mut dst: Owned = (0..1000).collect();
+
+		left.as_slice().union_into(right.as_slice(), &mut dst);
+
+		assert!(
+			dst.ascending().eq([1, 63, 64, 65, 300]),
+			"union should replace the destination values"
+		);
+
+		assert!(dst.validate(), "length should match the set bits");
+
+		left.as_slice()
+			.intersection_into(right.as_slice(), &mut dst);
+
+		assert!(
+			dst.ascending().eq([1, 64]),
+			"intersection should replace the destination values"
+		);
+
+		assert!(dst.validate(), "length should match the set bits");
+
+		left.as_slice().difference_into(right.as_slice(), &mut dst);
+
+		assert!(
+			dst.ascending().eq([63, 300]),
+			"difference should replace the destination values"
+		);
+
+		assert!(dst.validate(), "length should match the set bits");
+	}
+
+	#[test]
+	fn insert_slice() {
+		let mut set: Owned = [5].into_iter().collect();
+
+		assert_eq!(
+			set.insert_slice(&[300, 5, 63, 64, 300]),
+			3,
+			"only absent values should be counted once"
+		);
+
+		assert!(
+			set.ascending().eq([5, 63, 64, 300]),
+			"values should be inserted"
+		);
+		assert!(set.validate(), "length should match the set bits");
+		assert_eq!(set.insert_slice(&[]), 0, "no values should insert nothing");
+	}
+
+	#[test]
+	fn clear_range() {
+		let mut set: Owned = (0..200).collect();
+		let capacity = set.chunk_capacity();
+
+		set.clear_range(10, 130);
+
+		assert!(
+			set.ascending().eq((0..10).chain(130..200)),
+			"values in the range should be removed"
+		);
+
+		assert!(set.validate(), "length should match the set bits");
+
+		set.clear_range(190, 10_000);
+
+		assert!(
+			set.ascending().eq((0..10).chain(130..190)),
+			"range past the maximum should be clamped"
+		);
+
+		assert_eq!(set.chunk_capacity(), capacity, "capacity should be kept");
+	}
+
+	#[test]
+	fn retain_range() {
+		let mut set: Owned = (0..200).collect();
+
+		set.retain_range(60..130, |value| value % 2 == 0);
+
+		assert!(
+			set.ascending()
+				.eq((0..60).chain((60..130).step_by(2)).chain(130..200)),
+			"only values in the range should be filtered"
+		);
+
+		assert!(set.validate(), "length should match the set bits");
+
+		set.retain_range(190..10_000, |_| false);
+
+		assert_eq!(
+			set.as_slice().bounds(),
+			Some((0, 189)),
+			"range should be clamped"
+		);
+	}
+
+	#[test]
+	fn insert_range_count() {
+		let mut set = Owned::with_maximum(256);
+
+		set.insert_all(60, 70).unwrap();
+
+		assert_eq!(
+			set.insert_range_count(0, 128),
+			Some(118),
+			"only values not already present should be counted"
+		);
+
+		assert!(set.ascending().eq(0..128), "range should be inserted");
+		assert!(set.validate(), "length should match the set bits");
+
+		assert_eq!(
+			set.insert_range_count(200, 257),
+			None,
+			"range past the maximum should be rejected"
+		);
+	}
+
+	#[test]
+	fn first_missing_in() {
+		let set: Owned = (0..64).chain(65..130).collect();
+		let set = set.as_slice();
+
+		assert_eq!(
+			set.first_missing_in(0, 200),
+			Some(64),
+			"gap should be found"
+		);
+		assert_eq!(
+			set.first_missing_in(65, 200),
+			Some(130),
+			"search should start at `start`"
+		);
+
+		assert_eq!(
+			set.first_missing_in(65, 130),
+			None,
+			"full range should have no gap"
+		);
+		assert_eq!(
+			set.first_missing_in(500, 600),
+			Some(500),
+			"values past the maximum should be missing"
+		);
+	}
+
+	#[test]
+	fn copy_into_words() {
+		let mut set: Owned = [1, 64].into_iter().collect();
+
+		set.reserve_exact_chunks(4);
+
+		let mut words = [Inner::MAX; 3];
+
+		assert_eq!(
+			set.as_slice().copy_into_words(&mut words),
+			Ok(2),
+			"only the significant chunks should be written"
+		);
+
+		assert_eq!(words, [0b10, 1, 0], "rest of the buffer should be zeroed");
+
+		assert_eq!(
+			set.as_slice().copy_into_words(&mut words[..1]),
+			Err(2),
+			"small buffer should report the chunks needed"
+		);
+
+		assert_eq!(words, [0b10, 1, 0], "small buffer should be unchanged");
+	}
+
+	#[test]
+	fn with_words_mut() {
+		let mut set: Owned = [1, 2].into_iter().collect();
+
+		set.grow_maximum(128);
+		set.with_words_mut(|words| {
+			words[0] = 0;
+			words[1] = Inner::MAX;
+		});
+
+		assert_eq!(set.len(), 64, "length should be recounted");
+		assert!(set.ascending().eq(64..128), "changes should be kept");
+		assert_eq!(set.maximum(), 128, "set should not be grown");
+	}
+
+	#[test]
+	fn grow_maximum_capped() {
+		let mut set = Owned::new();
+
+		assert!(
+			set.grow_maximum_capped(100, 1000),
+			"growth below the cap should fit"
+		);
+		assert_eq!(
+			set.chunk_capacity(),
+			2,
+			"growth should allocate the needed chunks"
+		);
+
+		assert!(
+			!set.grow_maximum_capped(2000, 1000),
+			"growth beyond the cap should not fit"
+		);
+
+		assert_eq!(
+			set.maximum(),
+			960,
+			"growth should stop at the last whole chunk below the cap"
+		);
+	}
+
+	#[test]
+	fn union_intersection_len() {
+		let left: Owned = (0..1000).step_by(3).chain([5000]).collect();
+		let right: Owned = (0..700).step_by(5).collect();
+		let (left, right) = (left.as_slice(), right.as_slice());
+		let (only_left, both, only_right) = left.compare_counts(right);
+
+		assert_eq!(
+			left.union_len(right),
+			only_left + both + only_right,
+			"union length should count values in either set"
+		);
+
+		assert_eq!(
+			right.union_len(left),
+			left.union_len(right),
+			"union length should not depend on the order"
+		);
+
+		assert_eq!(
+			left.intersection_len(right),
+			both,
+			"intersection length should count values in both sets"
+		);
+
+		assert_eq!(
+			right.intersection_len(left),
+			both,
+			"intersection length should stop at the shorter set"
+		);
+	}
+
+	#[test]
+	fn from_words_counted() {
+		let set: Owned = (0..2000).step_by(7).chain([5000, 5001]).collect();
+		let counted = Borrowed::from_words_counted(set.as_words());
+
+		assert_eq!(
+			counted.len(),
+			Borrowed::from_words(set.as_words()).len(),
+			"vectorized count should match the const count"
+		);
+
+		assert_eq!(
+			counted.len(),
+			set.len(),
+			"count should match the set length"
+		);
+	}
+
+	#[test]
+	fn debug_truncated() {
+		let empty = Owned::new();
+		let exact: Owned = (0..16).collect();
+		let over: Owned = (0..17).collect();
+
+		assert_eq!(
+			alloc::format!("{empty:?}"),
+			"[]",
+			"empty set should format as an empty list"
+		);
+
+		assert_eq!(
+			alloc::format!("{exact:?}"),
+			"[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]",
+			"set at the limit should print every value"
+		);
+
+		assert_eq!(
+			alloc::format!("{over:?}"),
+			"[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, …, (17 total)]",
+			"set past the limit should stop and report its length"
+		);
+
+		assert_eq!(
+			alloc::format!("{:?}", over.as_slice().debug_full()),
+			"[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]",
+			"full listing of a slice should print every value"
+		);
+
+		assert_eq!(
+			alloc::format!(
+				"{:?}",
+				crate::SharedSet::from(over.clone()).as_slice().debug_full()
+			),
+			alloc::format!("{:?}", over.debug_full()),
+			"shared set should list the same values"
+		);
+	}
+
+	#[test]
+	fn from_btree_set() {
+		let values: BTreeSet<usize> = [0, 63, 64, 500].into_iter().collect();
+		let set = Owned::from(&values);
+
+		assert!(
+			set.ascending().eq(values.iter().copied()),
+			"set should hold the tree values"
+		);
+
+		assert_eq!(set.len(), 4, "length should match the tree");
+		assert_eq!(set.chunk_capacity(), 8, "set should fit the largest value");
+		assert!(set.validate(), "length should match the set bits");
+
+		let empty = Owned::from(&BTreeSet::new());
+
+		assert!(empty.is_empty(), "empty tree should give an empty set");
+		assert_eq!(empty.chunk_capacity(), 0, "empty tree should not allocate");
+	}
+
+	#[test]
+	fn eq_sorted() {
+		let set: Owned = [1, 63, 64, 200].into_iter().collect();
+		let set = set.as_slice();
+
+		assert!(
+			set.eq_sorted(&[1, 63, 64, 200]),
+			"same values should be equal"
+		);
+		assert!(
+			!set.eq_sorted(&[1, 63, 200]),
+			"missing value should not be equal"
+		);
+
+		assert!(
+			!set.eq_sorted(&[1, 63, 64, 200, 300]),
+			"extra value should not be equal"
+		);
+
+		assert!(
+			!set.eq_sorted(&[1, 64, 63, 200]),
+			"unsorted values should not be equal"
+		);
+
+		assert!(
+			!set.eq_sorted(&[1, 63, 63, 200]),
+			"duplicate values should not be equal"
+		);
+
+		assert!(
+			Owned::new().as_slice().eq_sorted(&[]),
+			"empty set should equal no values"
+		);
+	}
+
+	#[test]
+	fn ascending_peek() {
+		let set: Owned = [3, 64].into_iter().collect();
+		let mut iter = set.ascending();
+
+		assert_eq!(iter.peek(), Some(3), "peek should return the next value");
+		assert_eq!(iter.peek(), Some(3), "peek should not advance");
+		assert_eq!(iter.next(), Some(3), "next should return the peeked value");
+		assert_eq!(
+			iter.peek(),
+			Some(64),
+			"peek should cross the chunk boundary"
+		);
+		assert_eq!(iter.len(), 1, "peek should not consume values");
+
+		iter.next();
+
+		assert_eq!(iter.peek(), None, "exhausted iterator should peek nothing");
+
+		let mut truncated = set.as_slice().range(0, 10);
+
+		truncated.next();
+
+		assert_eq!(
+			truncated.peek(),
+			None,
+			"iterator should peek nothing once its length is used up"
+		);
+	}
+
+	#[test]
+	fn sub_slice() {
+		let left: Owned = [1, 63, 64, 200, 500].into_iter().collect();
+		let right: Owned = [63, 200, 700].into_iter().collect();
+		let result = &left - right.as_slice();
+
+		assert!(
+			result.ascending().eq([1, 64, 500]),
+			"difference should keep only values missing from the right"
+		);
+
+		assert_eq!(
+			result.len(),
+			left.len() - left.as_slice().intersection_len(right.as_slice()),
+			"length should drop by the shared values"
+		);
+
+		assert!(result.validate(), "length should match the set bits");
+	}
+
+	#[test]
+	fn count_chunks() {
+		let set: Owned = [0, 63, 64, 130, 191].into_iter().collect();
+		let set = set.as_slice();
+
+		assert_eq!(
+			set.count_chunks(0, 1),
+			2,
+			"first chunk should hold 2 values"
+		);
+		assert_eq!(
+			set.count_chunks(1, 3),
+			3,
+			"later chunks should hold 3 values"
+		);
+		assert_eq!(set.count_chunks(0, 100), 5, "end should clamp to the set");
+		assert_eq!(
+			set.count_chunks(5, 9),
+			0,
+			"chunks past the set should be empty"
+		);
+		assert_eq!(set.count_chunks(2, 1), 0, "reversed bounds should be empty");
+	}
+
+	#[test]
+	fn normalized_maximum() {
+		let mut set = Owned::with_maximum(640);
+
+		assert_eq!(
+			set.normalized_maximum(),
+			0,
+			"empty set should need no chunks"
+		);
+
+		set.insert(70);
+
+		assert_eq!(set.maximum(), 640, "set should keep its trailing chunks");
+
+		assert_eq!(
+			set.normalized_maximum(),
+			128,
+			"trailing empty chunks should not count"
+		);
+
+		assert_eq!(
+			set.as_slice().normalized_maximum(),
+			128,
+			"slice should agree with the set"
+		);
+
+		assert_eq!(
+			Owned::new().as_slice().normalized_maximum(),
+			0,
+			"set without chunks should need none"
+		);
+	}
+
+	#[test]
+	fn iter_offset() {
+		let set: Owned = [0, 63, 64].into_iter().collect();
+
+		assert!(
+			set.as_slice().iter_offset(10).eq([10, 73, 74]),
+			"values should be increased by the offset"
+		);
+
+		assert!(
+			set.as_slice().iter_offset(usize::MAX - 64).eq([
+				usize::MAX - 64,
+				usize::MAX - 1,
+				usize::MAX
+			]),
+			"values should reach the largest `usize`"
+		);
+	}
+
+	#[test]
+	#[should_panic = "offset value should fit in a `usize`"]
+	fn iter_offset_overflow() {
+		let set: Owned = [0, 65].into_iter().collect();
+
+		set.as_slice().iter_offset(usize::MAX - 64).for_each(drop);
+	}
+
+	#[test]
+	fn insert_or_grow() {
+		let mut set = Owned::new();
+
+		assert!(
+			!set.insert_or_grow(130),
+			"absent value should report being absent"
+		);
+		assert!(
+			set.insert_or_grow(130),
+			"present value should report being present"
+		);
+		assert_eq!(set.maximum(), 192, "set should grow to fit the value");
+		assert!(
+			!set.insert_or_grow(5),
+			"value below the maximum should not grow"
+		);
+		assert_eq!(set.maximum(), 192, "set should not grow for smaller values");
+		assert!(set.ascending().eq([5, 130]), "set should hold both values");
+	}
+
+	#[test]
+	fn extend_from_slice_values() {
+		let mut set: Owned = [1, 63].into_iter().collect();
+		let other: Owned = [63, 64, 300].into_iter().collect();
+
+		set.extend_from_slice_values(other.as_slice());
+
+		assert!(
+			set.ascending().eq([1, 63, 64, 300]),
+			"set should keep its values and gain the others"
+		);
+
+		assert_eq!(
+			set.maximum(),
+			other.maximum(),
+			"set should grow to fit `other`"
+		);
+		assert_eq!(set.len(), 4, "shared values should count once");
+		assert!(set.validate(), "length should match the set bits");
+
+		set.extend_from_slice_values(Owned::new().as_slice());
+
+		assert_eq!(set.len(), 4, "empty slice should change nothing");
+	}
+
+	#[test]
+	fn remove_report() {
+		let mut set: Owned = [3, 64].into_iter().collect();
+
+		assert_eq!(
+			set.remove_report(3),
+			Some((true, false)),
+			"removing one of two values should leave the set non-empty"
+		);
+
+		assert_eq!(
+			set.remove_report(3),
+			Some((false, false)),
+			"absent value should report being absent"
+		);
+
+		assert_eq!(
+			set.remove_report(64),
+			Some((true, true)),
+			"removing the last value should report the set as empty"
+		);
+
+		assert_eq!(
+			set.remove_report(1000),
+			None,
+			"value past the maximum should not fit"
+		);
+	}
+
+	#[test]
+	fn chunk_bits() {
+		let set: Owned = [1, 5, 64, 127].into_iter().collect();
+		let bits: crate::Bits = set.as_slice().chunk_bits(1);
+
+		assert!(
+			bits.eq([0, 63]),
+			"bits should be positions within the chunk"
+		);
+
+		assert!(
+			set.as_slice().chunk_bits(0).rev().eq([5, 1]),
+			"bits should iterate from both ends"
+		);
+
+		assert_eq!(
+			set.as_slice().chunk_bits(9).len(),
+			0,
+			"chunk past the set should have no bits"
+		);
+	}
+
+	#[test]
+	fn present_masks() {
+		let set: Owned = [0, 1, 64].into_iter().collect();
+		let set = set.as_slice();
+
+		assert!(
+			set.all_present_mask(0, 0b11),
+			"every masked bit should be present"
+		);
+		assert!(
+			!set.all_present_mask(0, 0b111),
+			"a masked bit should be missing"
+		);
+		assert!(
+			set.any_present_mask(0, 0b110),
+			"a masked bit should be present"
+		);
+		assert!(
+			!set.any_present_mask(1, 0b10),
+			"no masked bit should be present"
+		);
+		assert!(
+			set.all_present_mask(0, 0),
+			"zero mask should be trivially present"
+		);
+		assert!(
+			!set.any_present_mask(0, 0),
+			"zero mask should have no bit present"
+		);
+
+		assert!(
+			!set.all_present_mask(5, 1),
+			"chunk past the set should hold no bits"
+		);
+
+		assert!(
+			set.all_present_mask(5, 0),
+			"zero mask should be present past the set"
+		);
+
+		assert!(
+			!set.any_present_mask(5, Inner::MAX),
+			"chunk past the set should have no bit present"
+		);
+	}
+
+	#[test]
+	fn from_iter_with_hint() {
+		let set = Owned::from_iter_with_hint([3, 64, 200], 300);
+
+		assert!(
+			set.ascending().eq([3, 64, 200]),
+			"set should hold the values"
+		);
+		assert_eq!(set.maximum(), 320, "hint should size the buffer");
+
+		let set = Owned::from_iter_with_hint([3, 1000], 100);
+
+		assert!(
+			set.ascending().eq([3, 1000]),
+			"value above the hint should fit"
+		);
+		assert!(set.maximum() > 1000, "set should grow past the hint");
+	}
+
+	#[test]
+	fn whole_chunk_len_bounds() {
+		let mut set = Owned::with_maximum(192);
+
+		set.insert_all(0, 192);
+
+		assert_eq!(
+			set.len(),
+			set.maximum(),
+			"full set should reach its maximum"
+		);
+
+		set.remove_all(0, 192);
+
+		assert!(set.is_empty(), "emptied set should reach a length of 0");
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic = "`len` exceeds the stored bits"]
+	fn insert_chunk_miscounted_len() {
+		let mut set = Owned {
+			data: alloc::vec![0; 3].into_boxed_slice(),
+			len: 100,
+		};
+
+		set.insert_all(0, 192);
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic = "`len` is below the stored bits"]
+	fn remove_chunk_miscounted_len() {
+		let mut set = Owned {
+			data: alloc::vec![Inner::MAX; 3].into_boxed_slice(),
+			len: 100,
+		};
+
+		set.remove_all(0, 192);
+	}
+
+	#[test]
+	fn grow_insert_range_bounds() {
+		let mut set = Owned::new();
+
+		set.grow_insert_range(5..=70);
+
+		assert_eq!(set.len(), 66, "inclusive range should insert its end");
+		assert!(set.contains(70), "set should grow to fit the end");
+		assert!(!set.contains(71), "set should stop at the end");
+	}
+
+	#[test]
+	#[should_panic = "`range` should have an end to grow to"]
+	fn grow_insert_range_unbounded() {
+		Owned::new().grow_insert_range(5..);
 	}
 }
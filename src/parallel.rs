@@ -0,0 +1,101 @@
+use rayon::iter::{
+	plumbing::UnindexedConsumer, IndexedParallelIterator, IntoParallelIterator, ParallelIterator,
+};
+
+use crate::{
+	borrowed::Borrowed,
+	inner::{Inner, Iter},
+};
+
+/// A parallel iterator over values in a set.
+#[derive(Clone, Copy)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Parallel<'data> {
+	data: &'data [Inner],
+}
+
+impl<'data> ParallelIterator for Parallel<'data> {
+	type Item = usize;
+
+	#[inline]
+	fn drive_unindexed<C>(self, consumer: C) -> C::Result
+	where
+		C: UnindexedConsumer<Self::Item>,
+	{
+		self.data
+			.into_par_iter()
+			.enumerate()
+			.flat_map_iter(|(offset, &inner)| {
+				let base = crate::inner::chunk_to_bits(offset);
+
+				Iter::new(inner).map(move |index| base + usize::from(index))
+			})
+			.drive_unindexed(consumer)
+	}
+}
+
+impl<'data> Borrowed<'data> {
+	/// Returns the number of values in the set, counted in parallel.
+	#[inline]
+	#[must_use]
+	pub fn par_len(self) -> usize {
+		self.data
+			.into_par_iter()
+			.map(|inner| inner.count_ones() as usize)
+			.sum()
+	}
+}
+
+impl<'data> IntoParallelIterator for Borrowed<'data> {
+	type Iter = Parallel<'data>;
+	type Item = usize;
+
+	#[inline]
+	fn into_par_iter(self) -> Self::Iter {
+		Parallel { data: self.data }
+	}
+}
+
+impl<'data> IntoParallelIterator for &Borrowed<'data> {
+	type Iter = Parallel<'data>;
+	type Item = usize;
+
+	#[inline]
+	fn into_par_iter(self) -> Self::Iter {
+		(*self).into_par_iter()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use alloc::vec::Vec;
+
+	use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+	use crate::borrowed::Borrowed;
+
+	#[test]
+	fn parallel_matches_ascending() {
+		let data = [0b1011, 0, u64::MAX, 1 << 63];
+		let set = Borrowed::from_words(&data);
+		let list: Vec<_> = set.into_par_iter().collect();
+
+		assert!(
+			set.ascending().eq(list),
+			"parallel values should match the ascending order"
+		);
+	}
+
+	#[test]
+	fn parallel_len() {
+		let data = [0b1011, 0, u64::MAX, 1 << 63];
+		let set = Borrowed::from_words(&data);
+
+		assert_eq!(set.par_len(), set.len(), "parallel length should match");
+		assert_eq!(
+			Borrowed::from_words(&[]).par_len(),
+			0,
+			"empty set should have no values"
+		);
+	}
+}
@@ -0,0 +1,76 @@
+use core::iter::FusedIterator;
+
+use crate::inner::Inner;
+
+/// An iterator over the maximal runs of set bits within a sequence of chunks.
+#[derive(Clone)]
+pub struct Runs<F> {
+	source: F,
+	chunks: usize,
+	position: usize,
+}
+
+impl<F: Fn(usize) -> Inner> Runs<F> {
+	#[inline]
+	pub const fn new(source: F, chunks: usize) -> Self {
+		Self {
+			source,
+			chunks,
+			position: 0,
+		}
+	}
+
+	#[inline]
+	fn seek(&self, position: usize, state: bool) -> usize {
+		let chunk = |offset| {
+			let inner = (self.source)(offset);
+
+			if state {
+				inner
+			} else {
+				!inner
+			}
+		};
+
+		let start = crate::inner::bits_to_chunk(position);
+
+		if start >= self.chunks {
+			return crate::inner::chunk_to_bits(self.chunks);
+		}
+
+		let first = chunk(start) & !crate::inner::mask_below(position);
+
+		core::iter::once((start, first))
+			.chain((start + 1..self.chunks).map(|offset| (offset, chunk(offset))))
+			.find(|&(_, inner)| inner != 0)
+			.map_or(
+				crate::inner::chunk_to_bits(self.chunks),
+				|(offset, inner)| {
+					crate::inner::chunk_to_bits(offset) + inner.trailing_zeros() as usize
+				},
+			)
+	}
+}
+
+impl<F: Fn(usize) -> Inner> Iterator for Runs<F> {
+	type Item = (usize, usize);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let start = self.seek(self.position, true);
+
+		if start >= crate::inner::chunk_to_bits(self.chunks) {
+			self.position = start;
+
+			return None;
+		}
+
+		let end = self.seek(start, false);
+
+		self.position = end;
+
+		Some((start, end))
+	}
+}
+
+impl<F: Fn(usize) -> Inner> FusedIterator for Runs<F> {}
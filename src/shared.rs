@@ -0,0 +1,103 @@
+#[cfg(feature = "sync")]
+use alloc::sync::Arc;
+use alloc::{boxed::Box, rc::Rc};
+use core::ops::Deref;
+
+use crate::{
+	ascending::Ascending, borrowed::Borrowed, descending::Descending, inner::Inner, owned::Owned,
+};
+
+/// An immutable set whose chunks are shared through the pointer `P`,
+/// making clones cheap.
+#[derive(Clone)]
+pub struct Shared<P> {
+	data: P,
+	len: usize,
+}
+
+/// A set shared through [`Rc`].
+pub type SharedSet = Shared<Rc<[Inner]>>;
+
+/// A set shared through [`Arc`], which can be sent across threads.
+#[cfg(feature = "sync")]
+pub type SyncSharedSet = Shared<Arc<[Inner]>>;
+
+impl<P: Deref<Target = [Inner]>> Shared<P> {
+	/// Returns a lightweight borrow of the set.
+	#[inline]
+	#[must_use]
+	pub fn as_slice(&self) -> Borrowed<'_> {
+		// SAFETY: `len` was counted from the same chunks.
+		unsafe { Borrowed::new(&self.data, self.len) }
+	}
+
+	/// Returns the number of values in the set.
+	#[inline]
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns whether the set has no values.
+	#[inline]
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns whether the given index is in the set.
+	#[inline]
+	#[must_use]
+	pub fn contains(&self, value: usize) -> bool {
+		self.as_slice().contains(value)
+	}
+
+	/// Returns an ascending iterator over the stored values.
+	#[inline]
+	pub fn ascending(&self) -> Ascending<'_> {
+		self.as_slice().ascending()
+	}
+
+	/// Returns a descending iterator over the stored values.
+	#[inline]
+	pub fn descending(&self) -> Descending<'_> {
+		self.as_slice().descending()
+	}
+
+	/// Returns a copy of the set that can be changed.
+	#[inline]
+	#[must_use]
+	pub fn to_set(&self) -> Owned {
+		let mut set = Owned::new();
+
+		set.clone_from_slice(self.as_slice());
+		set
+	}
+}
+
+impl<P: From<Box<[Inner]>>> From<Owned> for Shared<P> {
+	#[inline]
+	fn from(set: Owned) -> Self {
+		let len = set.len();
+
+		Self {
+			data: P::from(set.into_words()),
+			len,
+		}
+	}
+}
+
+impl<P: Deref<Target = [Inner]>> PartialEq for Shared<P> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.as_slice().eq(&other.as_slice())
+	}
+}
+
+impl<P: Deref<Target = [Inner]>> Eq for Shared<P> {}
+
+impl<P: Deref<Target = [Inner]>> core::fmt::Debug for Shared<P> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.as_slice().fmt(f)
+	}
+}